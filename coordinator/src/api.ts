@@ -1,6 +1,7 @@
 import express, { Request, Response } from "express";
 import { recipientStore } from "./store";
-import { RegisterSchedulePayload, ScheduleRecipientData } from "./types";
+import { Recipient } from "@veil-dev/sdk";
+import { RegisterSchedulePayload, RegisterScheduleRecipient, ScheduleRecipientData } from "./types";
 import { executionRepository } from "./db/execution-repository";
 import { assertSchedulePayloadAccess, PayloadAccessError } from "./payload-auth";
 import {
@@ -201,10 +202,7 @@ router.get(
             scheduleId: data.scheduleId,
             vaultEmployer: data.vaultEmployer,
             tokenMint: data.tokenMint,
-            recipients: data.recipients.map((recipient) => ({
-                address: recipient.address.toBase58(),
-                amount: recipient.amount.toString(),
-            })),
+            recipients: data.recipients.map(toRegisterScheduleRecipient),
             merkleRoot: data.merkleRoot,
             createdAt: data.createdAt,
         });
@@ -289,10 +287,22 @@ function toComparablePayload(data: ScheduleRecipientData): RegisterSchedulePaylo
         scheduleId: data.scheduleId,
         vaultEmployer: data.vaultEmployer,
         tokenMint: data.tokenMint,
-        recipients: data.recipients.map((recipient) => ({
-            address: recipient.address.toBase58(),
-            amount: recipient.amount.toString(),
-        })),
+        recipients: data.recipients.map(toRegisterScheduleRecipient),
+    };
+}
+
+function toRegisterScheduleRecipient(recipient: Recipient): RegisterScheduleRecipient {
+    return {
+        address: recipient.address.toBase58(),
+        amount: recipient.amount.toString(),
+        ...(recipient.splits?.length
+            ? {
+                  splits: recipient.splits.map((split) => ({
+                      destination: split.destination.toBase58(),
+                      amount: split.amount.toString(),
+                  })),
+              }
+            : {}),
     };
 }
 
@@ -307,7 +317,15 @@ function isComparableRegistrationPayload(payload: Partial<RegisterSchedulePayloa
             (recipient) =>
                 recipient &&
                 typeof recipient.address === "string" &&
-                typeof recipient.amount === "string"
+                typeof recipient.amount === "string" &&
+                (recipient.splits === undefined ||
+                    (Array.isArray(recipient.splits) &&
+                        recipient.splits.every(
+                            (split) =>
+                                split &&
+                                typeof split.destination === "string" &&
+                                typeof split.amount === "string"
+                        )))
         )
     );
 }
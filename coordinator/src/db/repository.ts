@@ -17,6 +17,14 @@ export class ScheduleRepository {
             recipients: data.recipients.map(r => ({
                 address: r.address.toString(),
                 amount: r.amount.toString(),
+                ...(r.splits?.length
+                    ? {
+                          splits: r.splits.map(s => ({
+                              destination: s.destination.toString(),
+                              amount: s.amount.toString(),
+                          })),
+                      }
+                    : {}),
             })),
             proofs: data.proofs.map(p => ({
                 leafIndex: p.leafIndex,
@@ -95,6 +103,10 @@ export class ScheduleRepository {
             recipients: schedule.recipients.map(r => ({
                 address: new PublicKey(r.address),
                 amount: BigInt(r.amount),
+                splits: (r.splits ?? []).map(s => ({
+                    destination: new PublicKey(s.destination),
+                    amount: BigInt(s.amount),
+                })),
             })),
             proofs: schedule.proofs.map(p => ({
                 leafIndex: p.leafIndex,
@@ -21,7 +21,13 @@ export const schedules = pgTable(
         merkleRoot: text("merkle_root").notNull(),
         recipients: jsonb("recipients")
             .notNull()
-            .$type<Array<{ address: string; amount: string }>>(),
+            .$type<
+                Array<{
+                    address: string;
+                    amount: string;
+                    splits?: Array<{ destination: string; amount: string }>;
+                }>
+            >(),
         proofs: jsonb("proofs")
             .notNull()
             .$type<Array<{ leafIndex: number; proof: number[][] }>>(),
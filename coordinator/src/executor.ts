@@ -8,16 +8,18 @@ import {
 import { config } from "./config";
 import { ScheduleRecipientData } from "./types";
 import {
+    getBatchRecordPda,
     getConfigPda,
     getBufferPda,
     getDelegationMetadataPda,
     getDelegationRecordPda,
     DELEGATION_PROGRAM_ID,
-    getVaultPda,
     getVaultAtaPda,
     MAGIC_CONTEXT_ID,
     MAGIC_PROGRAM_ID,
     PROGRAM_ID,
+    getReceiptPda,
+    getScheduleIndexPda,
 } from "@veil-dev/sdk";
 import * as fs from "fs";
 import * as path from "path";
@@ -272,11 +274,25 @@ async function executeClaimsOnConnection(
     });
     const erProgram = new Program(idl as Idl, erProvider);
 
-    const vaultEmployer = new PublicKey(recipientData.vaultEmployer);
     const tokenMint = new PublicKey(recipientData.tokenMint);
-    const [vaultPda] = getVaultPda(vaultEmployer, tokenMint);
-    const [vaultAtaPda] = getVaultAtaPda(vaultPda);
     const [configPda] = getConfigPda();
+    // Claims in this run all belong to the batch after the last executed one
+    const schedule = await erProgram.account.scheduleAccount.fetch(schedulePda);
+    const batch = (schedule.lastExecutedBatch as BN).addn(1);
+    // The vault's address stays seeded by its original employer after an ownership transfer
+    const vaultPda = schedule.vault as PublicKey;
+    const [vaultAtaPda] = getVaultAtaPda(vaultPda);
+    // Fees go to the treasury's token account for this mint
+    const veilConfig = await erProgram.account.veilConfig.fetch(configPda);
+    const treasuryAta =
+        veilConfig.feeBps > 0
+            ? await getAssociatedTokenAddress(tokenMint, veilConfig.treasury as PublicKey)
+            : null;
+    const [batchRecordPda] = getBatchRecordPda(schedulePda, batch);
+    // The schedule index isn't delegated, so it can only be marked completed when claiming
+    // on the base layer; after a rollup run it is caught up through sync_schedule_index
+    const scheduleIndex =
+        config.claimExecutionLayer === "solana" ? getScheduleIndexPda(vaultPda)[0] : null;
     let successfulClaims = 0;
     let alreadyPaidClaims = 0;
     let failedClaims = 0;
@@ -288,6 +304,7 @@ async function executeClaimsOnConnection(
         const proof = recipientData.proofs[i];
         const recipientPubkey = new PublicKey(recipient.address);
         const amount = new BN(recipient.amount.toString());
+        const splits = recipient.splits ?? [];
 
         try {
             const recipientAta = await ensureRecipientAta(
@@ -303,7 +320,11 @@ async function executeClaimsOnConnection(
                     recipientPubkey,
                     amount,
                     proof.leafIndex,
-                    proof.proof
+                    proof.proof,
+                    splits.map((split) => ({
+                        destination: new PublicKey(split.destination),
+                        amount: new BN(split.amount.toString()),
+                    }))
                 )
                 .accountsStrict({
                     erAuthority: erAuthority.publicKey,
@@ -314,7 +335,19 @@ async function executeClaimsOnConnection(
                     recipientAta,
                     tokenMint,
                     tokenProgram: TOKEN_PROGRAM_ID,
+                    batchRecord: batchRecordPda,
+                    systemProgram: SystemProgram.programId,
+                    receipt: getReceiptPda(schedulePda, batch, proof.leafIndex)[0],
+                    treasuryAta,
+                    scheduleIndex,
                 })
+                .remainingAccounts(
+                    splits.map((split) => ({
+                        pubkey: new PublicKey(split.destination),
+                        isSigner: false,
+                        isWritable: true,
+                    }))
+                )
                 .transaction();
 
             // Send to ER
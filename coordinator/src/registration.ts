@@ -1,11 +1,17 @@
 import { AnchorProvider, Idl, Program, Wallet } from "@coral-xyz/anchor";
 import { Connection, Keypair, PublicKey } from "@solana/web3.js";
-import { buildMerkleTree, getSchedulePda, getVaultPda, Recipient } from "@veil-dev/sdk";
+import {
+    buildMerkleTree,
+    getSchedulePda,
+    MAX_PAYMENT_SPLITS,
+    PaymentSplit,
+    Recipient,
+} from "@veil-dev/sdk";
 import * as fs from "fs";
 import * as path from "path";
 import { config } from "./config";
 import { createLogger } from "./logger";
-import { RegisterSchedulePayload } from "./types";
+import { RegisterSchedulePayload, RegisterScheduleRecipient } from "./types";
 
 const connection = new Connection(config.solanaRpcUrl, "confirmed");
 const readOnlyWallet = new Wallet(Keypair.generate());
@@ -16,6 +22,7 @@ const idlPath = path.resolve(__dirname, "../../sdk/src/idl/idl.json");
 const idl = JSON.parse(fs.readFileSync(idlPath, "utf-8"));
 const program = new Program(idl as Idl, provider);
 const logger = createLogger("registration");
+const BPS_DENOMINATOR = 10_000n;
 
 export class RegistrationValidationError extends Error {
     constructor(
@@ -68,36 +75,35 @@ export async function validateScheduleRegistration(
     const employerPubkey = parsePublicKey(vaultEmployer, "vaultEmployer");
     const tokenMintPubkey = parsePublicKey(tokenMint, "tokenMint");
 
-    const recipientList = recipients.map((recipient, index) => ({
-        address: parsePublicKey(recipient.address, `recipients[${index}].address`),
-        amount: parseAmount(recipient.amount, `recipients[${index}].amount`),
-    }));
-
-    const [derivedVaultPda] = getVaultPda(employerPubkey, tokenMintPubkey);
-    const [derivedSchedulePda] = getSchedulePda(derivedVaultPda, scheduleId);
-    if (!schedulePubkey.equals(derivedSchedulePda)) {
-        logger.warn({ schedulePda, vaultEmployer }, "Schedule PDA does not match derived PDA");
-        throw new RegistrationValidationError("schedulePda does not match vaultEmployer + tokenMint + scheduleId");
-    }
+    const recipientList: Recipient[] = recipients.map((recipient, index) => {
+        const amount = parseAmount(recipient.amount, `recipients[${index}].amount`);
+        return {
+            address: parsePublicKey(recipient.address, `recipients[${index}].address`),
+            amount,
+            splits: parseSplits(recipient.splits, amount, `recipients[${index}].splits`),
+        };
+    });
 
     const accounts = program.account as any;
     const scheduleAccount: any = await fetchRequiredAccount(
         () => accounts.scheduleAccount.fetch(schedulePubkey),
         "schedule account not found"
     );
+
+    // Vault PDAs stay seeded by the original employer after an ownership transfer, so the
+    // vault is taken from the schedule and its current employer is checked on the vault
+    const vaultPubkey: PublicKey = scheduleAccount.vault;
+    const [derivedSchedulePda] = getSchedulePda(vaultPubkey, scheduleId);
+    if (!schedulePubkey.equals(derivedSchedulePda)) {
+        logger.warn({ schedulePda }, "Schedule PDA does not match derived PDA");
+        throw new RegistrationValidationError("schedulePda does not match the schedule's vault + scheduleId");
+    }
+
     const vaultAccount: any = await fetchRequiredAccount(
-        () => accounts.vaultAccount.fetch(derivedVaultPda),
+        () => accounts.vaultAccount.fetch(vaultPubkey),
         "vault account not found"
     );
 
-    if (scheduleAccount.employer.toString() !== employerPubkey.toString()) {
-        logger.warn({ schedulePda, vaultEmployer }, "Vault employer mismatch against on-chain schedule");
-        throw new RegistrationValidationError("vaultEmployer does not match the on-chain schedule");
-    }
-    if (scheduleAccount.vault.toString() !== derivedVaultPda.toString()) {
-        logger.warn({ schedulePda }, "Derived vault PDA mismatch against on-chain schedule");
-        throw new RegistrationValidationError("on-chain schedule vault does not match derived vault PDA");
-    }
     if (vaultAccount.employer.toString() !== employerPubkey.toString()) {
         logger.warn({ schedulePda, vaultEmployer }, "Vault employer mismatch against on-chain vault");
         throw new RegistrationValidationError("vaultEmployer does not match the on-chain vault");
@@ -119,7 +125,15 @@ export async function validateScheduleRegistration(
         totalRecipientAmount += recipient.amount;
     }
 
-    if (totalRecipientAmount !== BigInt(scheduleAccount.perExecutionAmount.toString())) {
+    // Bps leaves hold each recipient's share of perExecutionAmount, not a token amount
+    if (scheduleAccount.payoutMode?.bps !== undefined) {
+        if (totalRecipientAmount !== BPS_DENOMINATOR) {
+            logger.warn({ schedulePda }, "Recipient shares do not add up to the whole batch");
+            throw new RegistrationValidationError(
+                `sum of recipient shares must equal ${BPS_DENOMINATOR} bps`
+            );
+        }
+    } else if (totalRecipientAmount !== BigInt(scheduleAccount.perExecutionAmount.toString())) {
         logger.warn({ schedulePda }, "Recipient sum mismatch against on-chain perExecutionAmount");
         throw new RegistrationValidationError(
             "sum of recipient amounts must equal the on-chain perExecutionAmount"
@@ -170,7 +184,11 @@ export function isSameRegistrationPayload(
     for (let index = 0; index < existing.recipients.length; index += 1) {
         const left = existing.recipients[index];
         const right = incoming.recipients[index];
-        if (left.address !== right.address || left.amount !== right.amount) {
+        if (
+            left.address !== right.address ||
+            left.amount !== right.amount ||
+            !isSameSplits(left, right)
+        ) {
             return false;
         }
     }
@@ -178,6 +196,19 @@ export function isSameRegistrationPayload(
     return true;
 }
 
+function isSameSplits(left: RegisterScheduleRecipient, right: RegisterScheduleRecipient) {
+    const leftSplits = left.splits ?? [];
+    const rightSplits = right.splits ?? [];
+    return (
+        leftSplits.length === rightSplits.length &&
+        leftSplits.every(
+            (split, index) =>
+                split.destination === rightSplits[index].destination &&
+                split.amount === rightSplits[index].amount
+        )
+    );
+}
+
 function parsePublicKey(value: string, field: string): PublicKey {
     try {
         return new PublicKey(value);
@@ -201,6 +232,33 @@ function parseAmount(value: string, field: string): bigint {
     }
 }
 
+// Splits are part of the leaf hash, so they must match what the schedule's tree was built from
+function parseSplits(
+    splits: RegisterScheduleRecipient["splits"],
+    leafAmount: bigint,
+    field: string
+): PaymentSplit[] {
+    if (splits === undefined) {
+        return [];
+    }
+    if (!Array.isArray(splits)) {
+        throw new RegistrationValidationError(`${field} must be an array`);
+    }
+    if (splits.length > MAX_PAYMENT_SPLITS) {
+        throw new RegistrationValidationError(`${field} must have at most ${MAX_PAYMENT_SPLITS} entries`);
+    }
+
+    const parsed = splits.map((split, index) => ({
+        destination: parsePublicKey(split?.destination, `${field}[${index}].destination`),
+        amount: parseAmount(split?.amount, `${field}[${index}].amount`),
+    }));
+    const splitTotal = parsed.reduce((total, split) => total + split.amount, 0n);
+    if (splitTotal > leafAmount) {
+        throw new RegistrationValidationError(`${field} must not add up to more than the leaf amount`);
+    }
+    return parsed;
+}
+
 async function fetchRequiredAccount<T>(
     fetcher: () => Promise<T>,
     message: string
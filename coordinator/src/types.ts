@@ -12,12 +12,18 @@ export interface ScheduleRecipientData {
     createdAt: number;
 }
 
+export interface RegisterScheduleRecipient {
+    address: string;
+    amount: string;
+    splits?: Array<{ destination: string; amount: string }>;
+}
+
 export interface RegisterSchedulePayload {
     schedulePda: string;
     scheduleId: number[];
     vaultEmployer: string;
     tokenMint: string;
-    recipients: Array<{ address: string; amount: string }>;
+    recipients: RegisterScheduleRecipient[];
 }
 
 export type ExecutionRunStatus =
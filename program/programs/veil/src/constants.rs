@@ -3,3 +3,17 @@ pub const MIN_SCHEDULE_INTERVAL_SECS: u64 = 60 * 60;
 pub const MAX_SCHEDULE_INTERVAL_SECS: u64 = 31 * 24 * 60 * 60;
 pub const MAX_SCHEDULE_RECIPIENTS: u16 = 1024;
 pub const MAX_ALLOWED_MINTS: usize = 16;
+pub const MAX_ALLOWED_FUNDERS: usize = 8;
+pub const MAX_ALLOWED_DESTINATIONS: usize = 8;
+pub const MAX_APPROVERS: usize = 8;
+pub const MAX_ORG_ADMINS: usize = 8;
+pub const MAX_ORG_VAULTS: usize = 16;
+pub const MAX_ORG_OPERATORS: usize = 8;
+pub const MAX_WITHDRAW_WINDOW_SECS: u64 = 31 * 24 * 60 * 60;
+pub const WITHDRAW_LIMIT_LOOSEN_DELAY_SECS: u64 = 48 * 60 * 60;
+pub const MAX_AUTO_REFILL_CYCLES: u16 = 24;
+pub const MAX_CLAIM_WINDOW_SECS: u64 = 90 * 24 * 60 * 60;
+pub const MAX_PAYMENT_SPLITS: usize = 4;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const MAX_FEE_BPS: u16 = 500;
+pub const MAX_KEEPER_TIP_LAMPORTS: u64 = 10_000_000;
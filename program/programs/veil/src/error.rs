@@ -38,6 +38,9 @@ pub enum VeilProgramError {
     #[msg("Schedule is already cancelled")]
     ScheduleAlreadyCancelled,
 
+    #[msg("Schedule is already completed")]
+    ScheduleAlreadyCompleted,
+
     #[msg("Schedule is already paused")]
     ScheduleAlreadyPaused,
 
@@ -94,4 +97,190 @@ pub enum VeilProgramError {
 
     #[msg("Duplicate mint found in whitelist")]
     DuplicateAllowedMint,
+
+    #[msg("Invalid schedule start time")]
+    InvalidStartTime,
+
+    #[msg("Invalid schedule end time")]
+    InvalidEndTime,
+
+    #[msg("Invalid max executions")]
+    InvalidMaxExecutions,
+
+    #[msg("Claim exceeds the remaining per-execution amount")]
+    BatchAmountExceeded,
+
+    #[msg("Operation not supported for this schedule kind")]
+    InvalidScheduleKind,
+
+    #[msg("Amount exceeds the reserve held for future cycles")]
+    ExceedsFutureCycles,
+
+    #[msg("Invalid auto-refill target cycles")]
+    InvalidAutoRefillCycles,
+
+    #[msg("Recipient is withheld")]
+    RecipientWithheld,
+
+    #[msg("Invalid claim window")]
+    InvalidClaimWindow,
+
+    #[msg("Schedule rent budget cannot cover the account")]
+    InsufficientRentBudget,
+
+    #[msg("Account already exists")]
+    AccountAlreadyExists,
+
+    #[msg("Invalid batch record")]
+    InvalidBatchRecord,
+
+    #[msg("Claim window has expired")]
+    ClaimWindowExpired,
+
+    #[msg("Claim window is still open")]
+    ClaimWindowOpen,
+
+    #[msg("Receipt account required in receipt mode")]
+    ReceiptRequired,
+
+    #[msg("Invalid payment receipt")]
+    InvalidReceipt,
+
+    #[msg("Too many payment splits")]
+    TooManySplits,
+
+    #[msg("Invalid payment split")]
+    InvalidSplit,
+
+    #[msg("Basis points exceed 10000")]
+    InvalidBps,
+
+    #[msg("Invalid stream time range")]
+    InvalidStreamRange,
+
+    #[msg("Nothing accrued to claim")]
+    NothingToClaim,
+
+    #[msg("Invalid leaf claim account")]
+    InvalidLeafClaim,
+
+    #[msg("Invalid vesting grant")]
+    InvalidVestingGrant,
+
+    #[msg("Vesting already revoked")]
+    VestingAlreadyRevoked,
+
+    #[msg("Invalid protocol fee")]
+    InvalidFeeBps,
+
+    #[msg("Invalid treasury")]
+    InvalidTreasury,
+
+    #[msg("Keeper tip exceeds the maximum")]
+    InvalidKeeperTip,
+
+    #[msg("Vault tip budget is too low")]
+    InsufficientTipBudget,
+
+    #[msg("Funder is not allowed to deposit into this vault")]
+    FunderNotAllowed,
+
+    #[msg("Invalid funder allowlist")]
+    InvalidFunderAllowlist,
+
+    #[msg("Too many allowed funders configured")]
+    TooManyAllowedFunders,
+
+    #[msg("Duplicate funder in allowlist")]
+    DuplicateAllowedFunder,
+
+    #[msg("Destination is not allowed for withdrawals from this vault")]
+    DestinationNotAllowed,
+
+    #[msg("Invalid destination allowlist")]
+    InvalidDestinationAllowlist,
+
+    #[msg("Too many allowed destinations configured")]
+    TooManyAllowedDestinations,
+
+    #[msg("Duplicate destination in allowlist")]
+    DuplicateAllowedDestination,
+
+    #[msg("Vault token balance is below available + reserved")]
+    VaultInvariantViolation,
+
+    #[msg("Invalid withdraw limit")]
+    InvalidWithdrawLimit,
+
+    #[msg("Withdrawal exceeds the vault's limit for this window")]
+    WithdrawLimitExceeded,
+
+    #[msg("This action needs an approved pending action")]
+    ApprovalRequired,
+
+    #[msg("Approvals are not enabled for this vault")]
+    ApprovalsNotEnabled,
+
+    #[msg("Signer is not an approver for this vault")]
+    NotAnApprover,
+
+    #[msg("Approver has already approved this action")]
+    AlreadyApproved,
+
+    #[msg("Pending action does not have enough approvals")]
+    NotEnoughApprovals,
+
+    #[msg("Pending action does not match the requested action")]
+    ActionMismatch,
+
+    #[msg("Invalid approver set or threshold")]
+    InvalidApprovers,
+
+    #[msg("Too many approvers configured")]
+    TooManyApprovers,
+
+    #[msg("Duplicate approver")]
+    DuplicateApprover,
+
+    #[msg("Invalid operator permissions")]
+    InvalidOperatorPermissions,
+
+    #[msg("No ownership transfer pending for this signer")]
+    NoPendingOwnershipTransfer,
+
+    #[msg("Invalid organization admins")]
+    InvalidOrgAdmins,
+
+    #[msg("Signer is not an organization admin")]
+    NotAnOrgAdmin,
+
+    #[msg("Organization account does not match the vault")]
+    OrganizationMismatch,
+
+    #[msg("Vault already belongs to an organization")]
+    VaultAlreadyInOrganization,
+
+    #[msg("Organization has too many vaults")]
+    TooManyOrgVaults,
+
+    #[msg("Organization has too many operators")]
+    TooManyOrgOperators,
+
+    #[msg("Accounts do not match the organization's vaults")]
+    InvalidOrgVaults,
+
+    #[msg("Schedule is not in the vault's index")]
+    ScheduleNotIndexed,
+
+    #[msg("Only cancelled or completed schedules can be closed")]
+    ScheduleNotClosable,
+
+    #[msg("Schedule still has open batch records")]
+    OpenBatchRecords,
+
+    #[msg("Batch cannot be settled yet")]
+    BatchNotSettleable,
+
+    #[msg("Schedule has passed its end time")]
+    ScheduleEnded,
 }
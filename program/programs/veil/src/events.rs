@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{PayoutMode, ScheduleStatus, VaultAction};
+use crate::utils::PaymentSplit;
+
 #[event]
 pub struct ConfigInitialized {
     pub governance: Pubkey,
@@ -38,6 +41,14 @@ pub struct ErAuthorityUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ProtocolFeeUpdated {
+    pub governance: Pubkey,
+    pub fee_bps: u16,
+    pub treasury: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct VaultInitialized {
     pub employer: Pubkey,
@@ -50,6 +61,7 @@ pub struct VaultInitialized {
 pub struct VaultDeposited {
     pub vault: Pubkey,
     pub employer: Pubkey,
+    pub funder: Pubkey,
     pub amount: u64,
     pub available: u64,
 }
@@ -58,10 +70,216 @@ pub struct VaultDeposited {
 pub struct VaultWithdrawn {
     pub vault: Pubkey,
     pub employer: Pubkey,
+    pub destination: Pubkey,
     pub amount: u64,
     pub available: u64,
 }
 
+#[event]
+pub struct VaultReconciled {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub surplus: u64,
+    pub available: u64,
+    pub reserved: u64,
+}
+
+#[event]
+pub struct WithdrawLimitUpdated {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub withdraw_limit: u64,
+    pub withdraw_window_secs: u64,
+    pub effective_at: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationCreated {
+    pub organization: Pubkey,
+    pub creator: Pubkey,
+    pub name_hash: [u8; 32],
+    pub admins: Vec<Pubkey>,
+}
+
+#[event]
+pub struct OrganizationAdminsUpdated {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub admins: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationPolicyUpdated {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub default_withdraw_limit: u64,
+    pub default_withdraw_window_secs: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OrganizationOperatorUpdated {
+    pub organization: Pubkey,
+    pub admin: Pubkey,
+    pub operator: Pubkey,
+    pub permissions: u8, // 0 when removed
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultJoinedOrganization {
+    pub organization: Pubkey,
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+}
+
+#[event]
+pub struct VaultLeftOrganization {
+    pub organization: Pubkey,
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+}
+
+#[event]
+pub struct OrganizationTotalsRefreshed {
+    pub organization: Pubkey,
+    pub total_available: u64,
+    pub total_reserved: u64,
+    pub vault_count: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultOwnershipTransferStarted {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub pending_employer: Option<Pubkey>, // None when a pending transfer is cancelled
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultOwnershipTransferred {
+    pub vault: Pubkey,
+    pub previous_employer: Pubkey,
+    pub new_employer: Pubkey,
+    pub schedules_updated: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleEmployerSynced {
+    pub vault: Pubkey,
+    pub schedule: Pubkey,
+    pub employer: Pubkey,
+}
+
+#[event]
+pub struct ScheduleIndexSynced {
+    pub vault: Pubkey,
+    pub schedule: Pubkey,
+    pub status: ScheduleStatus,
+}
+
+#[event]
+pub struct ScheduleClosed {
+    pub vault: Pubkey,
+    pub schedule: Pubkey,
+    pub schedule_id: [u8; 32],
+    pub rent_returned: u64,
+}
+
+#[event]
+pub struct OperatorAdded {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub operator: Pubkey,
+    pub permissions: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OperatorRevoked {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ApproversUpdated {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub approvers: Vec<Pubkey>,
+    pub approval_threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ActionProposed {
+    pub vault: Pubkey,
+    pub pending_action: Pubkey,
+    pub nonce: u64,
+    pub action: VaultAction,
+}
+
+#[event]
+pub struct ActionApproved {
+    pub vault: Pubkey,
+    pub pending_action: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct ActionExecuted {
+    pub vault: Pubkey,
+    pub pending_action: Pubkey,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct ActionCancelled {
+    pub vault: Pubkey,
+    pub pending_action: Pubkey,
+    pub nonce: u64,
+}
+
+#[event]
+pub struct FunderAllowlistUpdated {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub allowlist_enabled: bool,
+    pub allowed_funders: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DestinationAllowlistUpdated {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub allowlist_enabled: bool,
+    pub allowed_destinations: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipBudgetFunded {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub lamports: u64,
+    pub tip_budget: u64,
+}
+
+#[event]
+pub struct TipBudgetWithdrawn {
+    pub vault: Pubkey,
+    pub employer: Pubkey,
+    pub lamports: u64,
+    pub tip_budget: u64,
+}
+
 #[event]
 pub struct VaultDelegated {
     pub vault: Pubkey,
@@ -93,6 +311,15 @@ pub struct ScheduleCancelled {
     pub returned_amount: u64,
 }
 
+#[event]
+pub struct ScheduleCompleted {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub released_amount: u64,
+    pub executed_batches: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SchedulePaused {
     pub employer: Pubkey,
@@ -119,6 +346,247 @@ pub struct ScheduleUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ScheduleFunded {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub amount: u64,
+    pub reserved_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleDefunded {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub amount: u64,
+    pub reserved_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AutoRefillUpdated {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub target_cycles: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleRefilled {
+    pub schedule: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub reserved_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleUnderfunded {
+    pub schedule: Pubkey,
+    pub vault: Pubkey,
+    pub refilled_amount: u64,
+    pub missing_amount: u64,
+    pub reserved_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleUpdateStaged {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_recipients: u16,
+    pub per_execution_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ScheduleUpdateApplied {
+    pub schedule: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_recipients: u16,
+    pub per_execution_amount: u64,
+    pub batch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientWithheld {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub leaf_index: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RecipientReleased {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub leaf_index: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ClaimWindowUpdated {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub claim_window_secs: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RentBudgetFunded {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub lamports: u64,
+    pub rent_budget: u64,
+}
+
+#[event]
+pub struct RentBudgetWithdrawn {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub lamports: u64,
+    pub rent_budget: u64,
+}
+
+#[event]
+pub struct BatchRecorded {
+    pub schedule: Pubkey,
+    pub batch_record: Pubkey,
+    pub batch: u64,
+    pub held_amount: u64,
+    pub expires_at: u64,
+}
+
+#[event]
+pub struct BatchRecordSkipped {
+    pub schedule: Pubkey,
+    pub batch: u64,
+    pub unclaimed_amount: u64,
+    pub rent_budget: u64,
+}
+
+#[event]
+pub struct BatchSettled {
+    pub schedule: Pubkey,
+    pub settler: Pubkey,
+    pub batch: u64,
+    pub paid_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PastBatchClaimed {
+    pub schedule: Pubkey,
+    pub batch: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub leaf_index: u16,
+    pub splits: Vec<PaymentSplit>,
+}
+
+#[event]
+pub struct BatchRecordClosed {
+    pub schedule: Pubkey,
+    pub batch: u64,
+    pub released_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptModeUpdated {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptsClosed {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub count: u16,
+    pub lamports: u64,
+    pub rent_budget: u64,
+}
+
+#[event]
+pub struct PayoutModeUpdated {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub payout_mode: PayoutMode,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DustReleased {
+    pub schedule: Pubkey,
+    pub vault: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreamClaimed {
+    pub schedule: Pubkey,
+    pub recipient: Pubkey,
+    pub leaf_index: u16,
+    pub amount: u64,
+    pub claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestedClaimed {
+    pub schedule: Pubkey,
+    pub recipient: Pubkey,
+    pub leaf_index: u16,
+    pub amount: u64,
+    pub claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub leaf_index: u16,
+    pub vested_amount: u64,
+    pub released_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeCollected {
+    pub schedule: Pubkey,
+    pub vault: Pubkey,
+    pub treasury: Pubkey,
+    pub payout: u64,
+    pub fee: u64,
+    pub paid: u64, // this fee plus any owed before, as far as available covered it
+    pub fees_owed: u64,
+    pub available: u64,
+}
+
+#[event]
+pub struct KeeperTipUpdated {
+    pub employer: Pubkey,
+    pub schedule: Pubkey,
+    pub keeper_tip: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KeeperTipped {
+    pub schedule: Pubkey,
+    pub vault: Pubkey,
+    pub keeper: Pubkey,
+    pub lamports: u64,
+    pub tip_budget: u64,
+}
+
 #[event]
 pub struct ScheduleDelegated {
     pub schedule: Pubkey,
@@ -138,6 +606,7 @@ pub struct PaymentClaimed {
     pub amount: u64,
     pub leaf_index: u16,
     pub paid_count: u16,
+    pub splits: Vec<PaymentSplit>, // Parts of amount not paid to the recipient
 }
 
 #[event]
@@ -22,6 +22,7 @@ pub struct InitConfig<'info> {
 }
 
 impl<'info> InitConfig<'info> {
+    #[allow(clippy::manual_range_contains)]
     pub fn init_config(
         &mut self,
         governance: Pubkey,
@@ -54,6 +55,8 @@ impl<'info> InitConfig<'info> {
             max_recipients,
             paused: false,
             batch_timeout_secs,
+            fee_bps: 0,
+            treasury: Pubkey::default(),
         });
 
         emit!(ConfigInitialized {
@@ -1,11 +1,13 @@
 pub mod init_config;
 pub mod pause;
 pub mod set_er_authority;
+pub mod set_protocol_fee;
 pub mod update_mint_whitelist;
 pub mod unpause;
 
 pub use init_config::*;
 pub use pause::*;
 pub use set_er_authority::*;
+pub use set_protocol_fee::*;
 pub use update_mint_whitelist::*;
 pub use unpause::*;
@@ -0,0 +1,42 @@
+use crate::error::VeilProgramError;
+use crate::events::ProtocolFeeUpdated;
+use crate::{VeilConfig, MAX_FEE_BPS};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetProtocolFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    pub governance: Signer<'info>,
+}
+
+impl<'info> SetProtocolFee<'info> {
+    pub fn set_protocol_fee(&mut self, fee_bps: u16, treasury: Pubkey) -> Result<()> {
+        require!(
+            self.governance.key() == self.config.governance,
+            VeilProgramError::Unauthorized
+        );
+        require!(fee_bps <= MAX_FEE_BPS, VeilProgramError::InvalidFeeBps);
+        require!(
+            fee_bps == 0 || treasury != Pubkey::default(),
+            VeilProgramError::InvalidTreasury
+        );
+
+        self.config.fee_bps = fee_bps;
+        self.config.treasury = treasury;
+
+        emit!(ProtocolFeeUpdated {
+            governance: self.governance.key(),
+            fee_bps,
+            treasury,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
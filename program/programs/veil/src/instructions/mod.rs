@@ -1,9 +1,11 @@
 pub mod admin;
 pub mod er;
+pub mod organization;
 pub mod schedule;
 pub mod vault;
 
 pub use admin::*;
 pub use er::*;
+pub use organization::*;
 pub use schedule::*;
 pub use vault::*;
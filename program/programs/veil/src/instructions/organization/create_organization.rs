@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::OrganizationCreated;
+use crate::{validate_org_admins, Organization, VeilConfig, ANCHOR_DISCRIMINATOR};
+
+#[derive(Accounts)]
+#[instruction(name_hash: [u8; 32])]
+pub struct CreateOrganization<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + Organization::INIT_SPACE,
+        seeds = [b"organization", creator.key().as_ref(), name_hash.as_ref()],
+        bump
+    )]
+    pub organization: Account<'info, Organization>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateOrganization<'info> {
+    pub fn create_organization(&mut self, name_hash: [u8; 32], admins: Vec<Pubkey>) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        validate_org_admins(&admins)?;
+
+        let creator_key = self.creator.key();
+        let seeds = &[b"organization", creator_key.as_ref(), name_hash.as_ref()];
+        let (_, bump) =
+            Pubkey::find_program_address(seeds, self.organization.to_account_info().owner);
+
+        self.organization.set_inner(Organization {
+            creator: creator_key,
+            name_hash,
+            admins: admins.clone(),
+            vaults: Vec::new(),
+            default_withdraw_limit: 0,
+            default_withdraw_window_secs: 0,
+            operators: Vec::new(),
+            total_available: 0,
+            total_reserved: 0,
+            totals_updated_at: 0,
+            bump,
+        });
+
+        emit!(OrganizationCreated {
+            organization: self.organization.key(),
+            creator: creator_key,
+            name_hash,
+            admins,
+        });
+
+        Ok(())
+    }
+}
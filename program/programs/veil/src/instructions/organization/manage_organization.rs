@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::{
+    OrganizationAdminsUpdated, OrganizationOperatorUpdated, OrganizationPolicyUpdated,
+};
+use crate::state::OrgOperator;
+use crate::{
+    validate_org_admins, Organization, VeilConfig, MAX_ORG_OPERATORS, MAX_WITHDRAW_WINDOW_SECS,
+    OPERATOR_ALL_PERMISSIONS,
+};
+
+#[derive(Accounts)]
+pub struct ManageOrganization<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.creator.as_ref(), organization.name_hash.as_ref()],
+        bump = organization.bump,
+    )]
+    pub organization: Account<'info, Organization>,
+}
+
+impl<'info> ManageOrganization<'info> {
+    pub fn update_organization_admins(&mut self, admins: Vec<Pubkey>) -> Result<()> {
+        self.require_admin()?;
+        validate_org_admins(&admins)?;
+
+        self.organization.admins = admins.clone();
+
+        emit!(OrganizationAdminsUpdated {
+            organization: self.organization.key(),
+            admin: self.admin.key(),
+            admins,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Set the withdraw limit used by member vaults that have no limit of their own.
+    pub fn set_organization_policy(
+        &mut self,
+        default_withdraw_limit: u64,
+        default_withdraw_window_secs: u64,
+    ) -> Result<()> {
+        self.require_admin()?;
+        if default_withdraw_limit > 0 {
+            require!(
+                default_withdraw_window_secs > 0
+                    && default_withdraw_window_secs <= MAX_WITHDRAW_WINDOW_SECS,
+                VeilProgramError::InvalidWithdrawLimit
+            );
+        } else {
+            require!(
+                default_withdraw_window_secs == 0,
+                VeilProgramError::InvalidWithdrawLimit
+            );
+        }
+
+        self.organization.default_withdraw_limit = default_withdraw_limit;
+        self.organization.default_withdraw_window_secs = default_withdraw_window_secs;
+
+        emit!(OrganizationPolicyUpdated {
+            organization: self.organization.key(),
+            admin: self.admin.key(),
+            default_withdraw_limit,
+            default_withdraw_window_secs,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Grant `operator` OPERATOR_* `permissions` on every member vault. 0 removes the role.
+    pub fn set_organization_operator(&mut self, operator: Pubkey, permissions: u8) -> Result<()> {
+        self.require_admin()?;
+        require!(
+            permissions & !OPERATOR_ALL_PERMISSIONS == 0 && operator != Pubkey::default(),
+            VeilProgramError::InvalidOperatorPermissions
+        );
+
+        let operators = &mut self.organization.operators;
+        operators.retain(|entry| entry.operator != operator);
+        if permissions != 0 {
+            require!(
+                operators.len() < MAX_ORG_OPERATORS,
+                VeilProgramError::TooManyOrgOperators
+            );
+            operators.push(OrgOperator {
+                operator,
+                permissions,
+            });
+        }
+
+        emit!(OrganizationOperatorUpdated {
+            organization: self.organization.key(),
+            admin: self.admin.key(),
+            operator,
+            permissions,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    fn require_admin(&self) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.organization.is_admin(&self.admin.key()),
+            VeilProgramError::NotAnOrgAdmin
+        );
+
+        Ok(())
+    }
+}
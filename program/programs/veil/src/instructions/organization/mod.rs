@@ -0,0 +1,9 @@
+pub mod create_organization;
+pub mod manage_organization;
+pub mod organization_membership;
+pub mod refresh_organization_totals;
+
+pub use create_organization::*;
+pub use manage_organization::*;
+pub use organization_membership::*;
+pub use refresh_organization_totals::*;
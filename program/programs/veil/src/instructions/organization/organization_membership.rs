@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::{VaultJoinedOrganization, VaultLeftOrganization};
+use crate::{Organization, VaultAccount, VeilConfig, MAX_ORG_VAULTS};
+
+/// Joining or leaving needs both the vault's employer and an organization admin.
+#[derive(Accounts)]
+pub struct OrganizationMembership<'info> {
+    pub employer: Signer<'info>,
+
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.creator.as_ref(), organization.name_hash.as_ref()],
+        bump = organization.bump,
+    )]
+    pub organization: Account<'info, Organization>,
+}
+
+impl<'info> OrganizationMembership<'info> {
+    pub fn join_organization(&mut self) -> Result<()> {
+        self.require_admin()?;
+        require!(
+            self.vault.organization.is_none(),
+            VeilProgramError::VaultAlreadyInOrganization
+        );
+        require!(
+            self.organization.vaults.len() < MAX_ORG_VAULTS,
+            VeilProgramError::TooManyOrgVaults
+        );
+
+        let vault_key = self.vault.key();
+        self.organization.vaults.push(vault_key);
+        self.vault.organization = Some(self.organization.key());
+
+        emit!(VaultJoinedOrganization {
+            organization: self.organization.key(),
+            vault: vault_key,
+            employer: self.employer.key(),
+        });
+
+        Ok(())
+    }
+
+    pub fn leave_organization(&mut self) -> Result<()> {
+        self.require_admin()?;
+        require!(
+            self.vault.organization == Some(self.organization.key()),
+            VeilProgramError::OrganizationMismatch
+        );
+
+        let vault_key = self.vault.key();
+        self.organization.vaults.retain(|vault| *vault != vault_key);
+        self.vault.organization = None;
+
+        emit!(VaultLeftOrganization {
+            organization: self.organization.key(),
+            vault: vault_key,
+            employer: self.employer.key(),
+        });
+
+        Ok(())
+    }
+
+    fn require_admin(&self) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.organization.is_admin(&self.admin.key()),
+            VeilProgramError::NotAnOrgAdmin
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::OrganizationTotalsRefreshed;
+use crate::{Organization, VaultAccount};
+
+#[derive(Accounts)]
+pub struct RefreshOrganizationTotals<'info> {
+    /// Anyone may refresh; totals are only read from the member vaults
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"organization", organization.creator.as_ref(), organization.name_hash.as_ref()],
+        bump = organization.bump,
+    )]
+    pub organization: Account<'info, Organization>,
+    // remaining_accounts: every vault in organization.vaults, in the same order
+}
+
+impl<'info> RefreshOrganizationTotals<'info> {
+    /// Recomputes available and reserved funds summed over all member vaults.
+    pub fn refresh_organization_totals(&mut self, vaults: &[AccountInfo<'info>]) -> Result<()> {
+        require!(
+            vaults.len() == self.organization.vaults.len(),
+            VeilProgramError::InvalidOrgVaults
+        );
+
+        let mut total_available: u64 = 0;
+        let mut total_reserved: u64 = 0;
+        for (vault_info, expected) in vaults.iter().zip(self.organization.vaults.iter()) {
+            require!(
+                vault_info.key() == *expected && vault_info.owner == &crate::ID,
+                VeilProgramError::InvalidOrgVaults
+            );
+            let vault = VaultAccount::try_deserialize(&mut &vault_info.try_borrow_data()?[..])?;
+
+            total_available = total_available
+                .checked_add(vault.available)
+                .ok_or(VeilProgramError::InsufficientFunds)?;
+            total_reserved = total_reserved
+                .checked_add(vault.reserved)
+                .ok_or(VeilProgramError::InsufficientFunds)?;
+        }
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        self.organization.total_available = total_available;
+        self.organization.total_reserved = total_reserved;
+        self.organization.totals_updated_at = timestamp;
+
+        emit!(OrganizationTotalsRefreshed {
+            organization: self.organization.key(),
+            total_available,
+            total_reserved,
+            vault_count: vaults.len() as u16,
+            timestamp,
+        });
+
+        Ok(())
+    }
+}
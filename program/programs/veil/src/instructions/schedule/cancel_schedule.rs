@@ -3,12 +3,17 @@ use anchor_lang::prelude::*;
 use crate::error::VeilProgramError;
 use crate::events::ScheduleCancelled;
 use crate::state::ScheduleStatus;
-use crate::{ScheduleAccount, VeilConfig, VaultAccount};
+use crate::utils::consume_approved_action;
+use crate::{
+    require_employer_or_operator, Operator, Organization, PendingAction, ScheduleAccount,
+    VaultAccount, VaultAction, VaultScheduleIndex, VeilConfig, OPERATOR_CANCEL,
+};
 
 #[derive(Accounts)]
 pub struct CancelSchedule<'info> {
+    /// Employer, or an operator allowed to cancel schedules
     #[account(mut)]
-    pub employer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         seeds = [b"veil_config"],
@@ -18,27 +23,61 @@ pub struct CancelSchedule<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", employer.key().as_ref(), vault.token_mint.as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
         bump = vault.bump,
-        has_one = employer @ VeilProgramError::Unauthorized,
     )]
     pub vault: Account<'info, VaultAccount>,
 
     #[account(
         mut,
-        has_one = employer @ VeilProgramError::Unauthorized,
         has_one = vault @ VeilProgramError::VaultMismatch,
     )]
     pub schedule: Account<'info, ScheduleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
 }
 
 impl<'info> CancelSchedule<'info> {
     pub fn cancel_schedule(&mut self) -> Result<()> {
         require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_CANCEL,
+        )?;
         require!(
             self.schedule.status != ScheduleStatus::Cancelled,
             VeilProgramError::ScheduleAlreadyCancelled
         );
+        require!(
+            self.schedule.status != ScheduleStatus::Completed,
+            VeilProgramError::ScheduleAlreadyCompleted
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.authority.to_account_info(),
+            VaultAction::CancelSchedule {
+                schedule: self.schedule.key(),
+            },
+        )?;
 
         let returned_amount = self.schedule.reserved_amount;
 
@@ -56,9 +95,11 @@ impl<'info> CancelSchedule<'info> {
 
         // Mark schedule as cancelled
         self.schedule.status = ScheduleStatus::Cancelled;
+        self.schedule_index
+            .set_status(&self.schedule.key(), ScheduleStatus::Cancelled)?;
 
         emit!(ScheduleCancelled {
-            employer: self.employer.key(),
+            employer: self.vault.employer,
             schedule: self.schedule.key(),
             returned_amount,
         });
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::VeilProgramError;
+use crate::events::PastBatchClaimed;
+use crate::utils::merkle::{hash_leaf_with_splits, verify_merkle_proof, PaymentSplit};
+use crate::utils::{collect_fee, pay_keeper_tip, transfer_with_splits};
+use crate::{payout_for, BatchRecord, ScheduleAccount, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+#[instruction(schedule_id: [u8; 32], batch: u64)]
+pub struct ClaimPastBatch<'info> {
+    /// CHECK: ER authority signer, paid the schedule's keeper tip
+    #[account(mut)]
+    pub er_authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = token_mint @ VeilProgramError::InvalidMint,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        address = vault.vault_ata @ VeilProgramError::InvalidTokenAccount
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"schedule", schedule.vault.as_ref(), schedule_id.as_ref()],
+        bump = schedule.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"batch_record", schedule.key().as_ref(), batch.to_le_bytes().as_ref()],
+        bump = batch_record.bump,
+        has_one = schedule @ VeilProgramError::InvalidBatchRecord,
+    )]
+    pub batch_record: Account<'info, BatchRecord>,
+
+    #[account(mut)]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Treasury token account; required while a protocol fee is set
+    #[account(mut)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+}
+
+impl<'info> ClaimPastBatch<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_past_batch(
+        &mut self,
+        _schedule_id: [u8; 32],
+        batch: u64,
+        recipient: Pubkey,
+        amount: u64,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
+        splits: Vec<PaymentSplit>,
+        split_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.er_authority.key() == self.config.er_authority,
+            VeilProgramError::Unauthorized
+        );
+
+        let clock = Clock::get()?;
+        require!(
+            (clock.unix_timestamp as u64) < self.batch_record.expires_at,
+            VeilProgramError::ClaimWindowExpired
+        );
+        require!(
+            leaf_index < self.batch_record.total_recipients,
+            VeilProgramError::InvalidLeafIndex
+        );
+        require!(
+            self.recipient_ata.owner == recipient,
+            VeilProgramError::Unauthorized
+        );
+        require!(
+            self.recipient_ata.mint == self.token_mint.key(),
+            VeilProgramError::InvalidMint
+        );
+
+        // Verify against the root the batch was paid from
+        let leaf = hash_leaf_with_splits(&recipient, amount, &splits);
+        require!(
+            verify_merkle_proof(leaf, &proof, leaf_index, self.batch_record.merkle_root),
+            VeilProgramError::InvalidMerkleProof
+        );
+
+        let byte_index = (leaf_index / 8) as usize;
+        let bit_index = (leaf_index % 8) as u8;
+        let is_paid = (self.batch_record.paid_bitmap[byte_index] >> bit_index) & 1 == 1;
+        require!(!is_paid, VeilProgramError::AlreadyPaid);
+        let is_withheld = (self.batch_record.withheld_bitmap[byte_index] >> bit_index) & 1 == 1;
+        require!(!is_withheld, VeilProgramError::RecipientWithheld);
+        // Bps leaves are paid from the per_execution_amount the batch ran with
+        let record = &self.batch_record;
+        let payout = payout_for(record.payout_mode, record.per_execution_amount, amount)?;
+        let paid_splits = splits
+            .iter()
+            .map(|split| {
+                Ok(PaymentSplit {
+                    destination: split.destination,
+                    amount: payout_for(
+                        record.payout_mode,
+                        record.per_execution_amount,
+                        split.amount,
+                    )?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        require!(
+            payout > 0 && payout <= record.held_amount,
+            VeilProgramError::BatchAmountExceeded
+        );
+
+        transfer_with_splits(
+            &self.vault,
+            &self.vault_ata,
+            self.recipient_ata.to_account_info(),
+            &self.token_program,
+            payout,
+            &paid_splits,
+            split_accounts,
+        )?;
+
+        collect_fee(
+            &self.config,
+            &mut self.vault,
+            &self.vault_ata,
+            self.treasury_ata.as_ref(),
+            &self.token_program,
+            self.schedule.key(),
+            payout,
+        )?;
+        pay_keeper_tip(
+            &mut self.vault,
+            &self.er_authority.to_account_info(),
+            self.schedule.key(),
+            self.schedule.keeper_tip,
+        )?;
+
+        self.batch_record.paid_bitmap[byte_index] |= 1 << bit_index;
+        self.batch_record.held_amount = self
+            .batch_record
+            .held_amount
+            .checked_sub(payout)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_sub(payout)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(PastBatchClaimed {
+            schedule: self.schedule.key(),
+            batch,
+            recipient,
+            amount: payout,
+            leaf_index,
+            splits: paid_splits,
+        });
+
+        Ok(())
+    }
+}
@@ -1,11 +1,18 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::error::VeilProgramError;
 use crate::events::PaymentClaimed;
-use crate::state::ScheduleStatus;
-use crate::utils::merkle::{hash_leaf, verify_merkle_proof};
-use crate::{ScheduleAccount, VaultAccount, VeilConfig};
+use crate::state::{payout_for, PayoutMode, ScheduleKind, ScheduleStatus};
+use crate::utils::merkle::{hash_leaf_with_splits, verify_merkle_proof, PaymentSplit};
+use crate::utils::{
+    apply_pending_update, collect_fee, create_pda_from_budget, pay_keeper_tip, settle_batch,
+    transfer_with_splits,
+};
+use crate::{
+    PaymentReceipt, ScheduleAccount, VaultAccount, VaultScheduleIndex, VeilConfig,
+    ANCHOR_DISCRIMINATOR,
+};
 
 #[derive(Accounts)]
 #[instruction(
@@ -15,7 +22,8 @@ use crate::{ScheduleAccount, VaultAccount, VeilConfig};
     leaf_index: u16,
 )]
 pub struct ClaimPayment<'info> {
-    /// CHECK: ER authority signer
+    /// CHECK: ER authority signer, paid the schedule's keeper tip
+    #[account(mut)]
     pub er_authority: Signer<'info>,
 
     #[account(
@@ -26,7 +34,7 @@ pub struct ClaimPayment<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", vault.employer.as_ref(), vault.token_mint.as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
         bump = vault.bump,
         has_one = token_mint @ VeilProgramError::InvalidMint,
     )]
@@ -42,6 +50,7 @@ pub struct ClaimPayment<'info> {
         mut,
         seeds = [b"schedule", schedule.vault.as_ref(), schedule_id.as_ref()],
         bump = schedule.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
     )]
     pub schedule: Account<'info, ScheduleAccount>,
 
@@ -51,9 +60,35 @@ pub struct ClaimPayment<'info> {
     pub token_mint: Account<'info, Mint>,
 
     pub token_program: Program<'info, Token>,
+
+    /// CHECK: Batch record PDA for the current batch, created from the schedule's rent
+    /// budget only if this claim closes a batch with unclaimed pay and a claim window is set
+    #[account(mut)]
+    pub batch_record: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    /// CHECK: PaymentReceipt PDA for this leaf, created from the schedule's rent budget
+    /// when receipt mode is on
+    #[account(mut)]
+    pub receipt: Option<UncheckedAccount<'info>>,
+
+    /// Treasury token account; required while a protocol fee is set
+    #[account(mut)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+
+    /// Marked completed if this claim finishes the schedule; the index isn't delegated,
+    /// so it is left out in the rollup and caught up through `sync_schedule_index`
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+    )]
+    pub schedule_index: Option<Account<'info, VaultScheduleIndex>>,
 }
 
 impl<'info> ClaimPayment<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn claim_payment(
         &mut self,
         _schedule_id: [u8; 32],
@@ -61,6 +96,8 @@ impl<'info> ClaimPayment<'info> {
         amount: u64,
         leaf_index: u16,
         proof: Vec<[u8; 32]>,
+        splits: Vec<PaymentSplit>,
+        split_accounts: &[AccountInfo<'info>],
     ) -> Result<()> {
         // Verify ER authority
         require!(
@@ -73,6 +110,13 @@ impl<'info> ClaimPayment<'info> {
             self.schedule.status == ScheduleStatus::Active,
             VeilProgramError::ScheduleNotActive
         );
+        require!(
+            matches!(
+                self.schedule.kind,
+                ScheduleKind::Interval | ScheduleKind::OneShot
+            ),
+            VeilProgramError::InvalidScheduleKind
+        );
 
         // Verify timing
         let clock = Clock::get()?;
@@ -81,10 +125,15 @@ impl<'info> ClaimPayment<'info> {
             current_time >= self.schedule.next_execution,
             VeilProgramError::ExecutionTooEarly
         );
+        require!(
+            !self.schedule.has_ended(current_time),
+            VeilProgramError::ScheduleEnded
+        );
 
         // Set batch start time if this is the first claim of the batch
         if self.schedule.batch_start_time == 0 {
             self.schedule.batch_start_time = current_time;
+            apply_pending_update(&mut self.schedule, clock.unix_timestamp);
         }
 
         // Verify leaf index is valid
@@ -104,7 +153,7 @@ impl<'info> ClaimPayment<'info> {
         );
 
         // Verify Merkle proof
-        let leaf = hash_leaf(&recipient, amount);
+        let leaf = hash_leaf_with_splits(&recipient, amount, &splits);
         require!(
             verify_merkle_proof(leaf, &proof, leaf_index, self.schedule.merkle_root),
             VeilProgramError::InvalidMerkleProof
@@ -119,29 +168,72 @@ impl<'info> ClaimPayment<'info> {
         );
         let is_paid = (self.schedule.paid_bitmap[byte_index] >> bit_index) & 1 == 1;
         require!(!is_paid, VeilProgramError::AlreadyPaid);
+        let is_withheld = (self.schedule.withheld_bitmap[byte_index] >> bit_index) & 1 == 1;
+        require!(!is_withheld, VeilProgramError::RecipientWithheld);
+
+        // In Bps mode the leaf value (and each split) is a share of per_execution_amount
+        let payout = self.schedule.leaf_payout(amount)?;
+        let paid_splits = splits
+            .iter()
+            .map(|split| {
+                Ok(PaymentSplit {
+                    destination: split.destination,
+                    amount: self.schedule.leaf_payout(split.amount)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-        // Verify amount doesn't exceed per_execution_amount
+        // Verify amount fits in what is left of this batch's per_execution_amount
+        let batch_paid_amount = self
+            .schedule
+            .batch_paid_amount
+            .checked_add(payout)
+            .ok_or(VeilProgramError::BatchAmountExceeded)?;
         require!(
-            amount > 0 && amount <= self.schedule.per_execution_amount,
-            VeilProgramError::InsufficientFunds
+            payout > 0
+                && batch_paid_amount <= self.schedule.per_execution_amount
+                && payout <= self.schedule.reserved_amount,
+            VeilProgramError::BatchAmountExceeded
         );
 
-        // Transfer tokens from vault_ata to recipient_ata
-        // Use vault PDA as signer
-        let employer_key = self.vault.employer;
-        let token_mint_key = self.vault.token_mint;
-        let bump = self.vault.bump;
-        let seeds = &[b"vault", employer_key.as_ref(), token_mint_key.as_ref(), &[bump]];
-        let signer = &[&seeds[..]];
-
-        let cpi_accounts = Transfer {
-            from: self.vault_ata.to_account_info(),
-            to: self.recipient_ata.to_account_info(),
-            authority: self.vault.to_account_info(),
-        };
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-        token::transfer(cpi_ctx, amount)?;
+        // Transfer tokens from vault_ata to the split accounts and recipient_ata
+        transfer_with_splits(
+            &self.vault,
+            &self.vault_ata,
+            self.recipient_ata.to_account_info(),
+            &self.token_program,
+            payout,
+            &paid_splits,
+            split_accounts,
+        )?;
+
+        collect_fee(
+            &self.config,
+            &mut self.vault,
+            &self.vault_ata,
+            self.treasury_ata.as_ref(),
+            &self.token_program,
+            self.schedule.key(),
+            payout,
+        )?;
+        pay_keeper_tip(
+            &mut self.vault,
+            &self.er_authority.to_account_info(),
+            self.schedule.key(),
+            self.schedule.keeper_tip,
+        )?;
+
+        // The payout has left vault_ata, so it leaves the reserve now rather than at settlement
+        self.schedule.reserved_amount = self
+            .schedule
+            .reserved_amount
+            .checked_sub(payout)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_sub(payout)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
 
         // Mark as paid in bitmap
         self.schedule.paid_bitmap[byte_index] |= 1 << bit_index;
@@ -152,54 +244,131 @@ impl<'info> ClaimPayment<'info> {
             .paid_count
             .checked_add(1)
             .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.schedule.batch_paid_amount = batch_paid_amount;
+        if self.schedule.payout_mode == PayoutMode::Bps {
+            self.track_dust(amount)?;
+        }
+
+        if self.schedule.receipt_mode {
+            self.write_receipt(recipient, payout, leaf_index, clock.unix_timestamp)?;
+        }
 
-        // Check if we should advance to next interval
-        let all_claimed = self.schedule.paid_count >= self.schedule.total_recipients;
-        let timeout_passed = self.schedule.batch_start_time > 0
-            && current_time
-                >= self
-                    .schedule
-                    .batch_start_time
-                    .checked_add(self.config.batch_timeout_secs)
-                    .ok_or(VeilProgramError::InsufficientFunds)?;
+        // Check if we should advance to next interval (withheld leaves don't hold a batch open)
+        let all_claimed = self.schedule.settled_leaf_count() >= self.schedule.total_recipients;
+        let timeout_passed = self
+            .schedule
+            .batch_timed_out(current_time, self.config.batch_timeout_secs);
         let has_claims = self.schedule.paid_count > 0;
 
         // Advance if all claimed OR (timeout passed and at least one claimed)
         if all_claimed || (timeout_passed && has_claims) {
-            // Deduct per_execution_amount from reserved
-            self.schedule.reserved_amount = self
-                .schedule
-                .reserved_amount
-                .checked_sub(self.schedule.per_execution_amount)
-                .ok_or(VeilProgramError::InsufficientFunds)?;
-
-            // Update vault reserved amount
-            self.vault.reserved = self
-                .vault
-                .reserved
-                .checked_sub(self.schedule.per_execution_amount)
-                .ok_or(VeilProgramError::InsufficientFunds)?;
-
-            // Reset paid_count, bitmap, batch_start_time, and advance next_execution
-            self.schedule.paid_count = 0;
-            self.schedule.paid_bitmap = [0u8; 128]; // Clear bitmap
-            self.schedule.batch_start_time = 0; // Reset for next batch
-            self.schedule.next_execution = current_time + self.schedule.interval_secs;
-            self.schedule.last_executed_batch = self
-                .schedule
-                .last_executed_batch
-                .checked_add(1)
-                .ok_or(VeilProgramError::InsufficientFunds)?;
+            settle_batch(
+                &mut self.schedule,
+                &mut self.vault,
+                self.schedule_index.as_mut(),
+                self.batch_record.as_ref(),
+                self.system_program.as_ref(),
+                current_time,
+                clock.unix_timestamp,
+            )?;
         }
 
         emit!(PaymentClaimed {
             schedule: self.schedule.key(),
             recipient,
-            amount,
+            amount: payout,
             leaf_index,
             paid_count: self.schedule.paid_count,
+            splits: paid_splits,
         });
 
         Ok(())
     }
+
+    /// Adds a claimed share and recomputes what rounding has left unpaid so far this batch.
+    fn track_dust(&mut self, bps: u64) -> Result<()> {
+        self.schedule.batch_paid_bps = self
+            .schedule
+            .batch_paid_bps
+            .checked_add(bps)
+            .ok_or(VeilProgramError::InvalidBps)?;
+        let owed = payout_for(
+            PayoutMode::Bps,
+            self.schedule.per_execution_amount,
+            self.schedule.batch_paid_bps,
+        )?;
+        self.schedule.batch_dust = owed
+            .checked_sub(self.schedule.batch_paid_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        Ok(())
+    }
+
+    /// Records the claim in a `PaymentReceipt` PDA seeded by `(schedule, batch, leaf_index)`.
+    fn write_receipt(
+        &mut self,
+        recipient: Pubkey,
+        amount: u64,
+        leaf_index: u16,
+        timestamp: i64,
+    ) -> Result<()> {
+        let (Some(receipt), Some(system_program)) = (&self.receipt, &self.system_program) else {
+            return err!(VeilProgramError::ReceiptRequired);
+        };
+
+        let schedule_key = self.schedule.key();
+        let batch = self
+            .schedule
+            .last_executed_batch
+            .checked_add(1)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        let batch_bytes = batch.to_le_bytes();
+        let leaf_bytes = leaf_index.to_le_bytes();
+        let (receipt_pda, bump) = Pubkey::find_program_address(
+            &[
+                b"receipt",
+                schedule_key.as_ref(),
+                batch_bytes.as_ref(),
+                leaf_bytes.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require!(
+            receipt.key() == receipt_pda,
+            VeilProgramError::InvalidReceipt
+        );
+
+        let lamports = create_pda_from_budget(
+            &self.schedule.to_account_info(),
+            &receipt.to_account_info(),
+            &system_program.to_account_info(),
+            ANCHOR_DISCRIMINATOR + PaymentReceipt::INIT_SPACE,
+            &[
+                b"receipt",
+                schedule_key.as_ref(),
+                batch_bytes.as_ref(),
+                leaf_bytes.as_ref(),
+                &[bump],
+            ],
+        )?;
+        self.schedule.rent_budget = self
+            .schedule
+            .rent_budget
+            .checked_sub(lamports)
+            .ok_or(VeilProgramError::InsufficientRentBudget)?;
+
+        let record = PaymentReceipt {
+            schedule: schedule_key,
+            batch,
+            leaf_index,
+            recipient,
+            amount,
+            mint: self.token_mint.key(),
+            timestamp,
+            bump,
+        };
+        record.try_serialize(&mut &mut receipt.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,183 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::error::VeilProgramError;
+use crate::events::VestedClaimed;
+use crate::state::{ScheduleKind, ScheduleStatus, VestingGrant};
+use crate::utils::merkle::{hash_vesting_leaf, verify_merkle_proof};
+use crate::utils::{collect_fee, pay_keeper_tip, transfer_from_vault};
+use crate::{LeafClaim, ScheduleAccount, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+#[instruction(schedule_id: [u8; 32])]
+pub struct ClaimVested<'info> {
+    /// ER authority, or the recipient withdrawing their own vested tokens
+    #[account(mut)]
+    pub claimer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = token_mint @ VeilProgramError::InvalidMint,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        address = vault.vault_ata @ VeilProgramError::InvalidTokenAccount
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule", schedule.vault.as_ref(), schedule_id.as_ref()],
+        bump = schedule.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    /// CHECK: LeafClaim PDA for this leaf, created from the schedule's rent budget on first claim
+    #[account(mut)]
+    pub leaf_claim: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub recipient_ata: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Treasury token account; required while a protocol fee is set
+    #[account(mut)]
+    pub treasury_ata: Option<Account<'info, TokenAccount>>,
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn claim_vested(
+        &mut self,
+        _schedule_id: [u8; 32],
+        recipient: Pubkey,
+        grant: VestingGrant,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.claimer.key() == self.config.er_authority || self.claimer.key() == recipient,
+            VeilProgramError::Unauthorized
+        );
+        require!(
+            self.schedule.status == ScheduleStatus::Active,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            self.schedule.kind == ScheduleKind::Vesting,
+            VeilProgramError::InvalidScheduleKind
+        );
+        require!(
+            leaf_index < self.schedule.total_recipients,
+            VeilProgramError::InvalidLeafIndex
+        );
+        grant.validate()?;
+        require!(
+            self.recipient_ata.owner == recipient,
+            VeilProgramError::Unauthorized
+        );
+        require!(
+            self.recipient_ata.mint == self.token_mint.key(),
+            VeilProgramError::InvalidMint
+        );
+
+        // Verify Merkle proof
+        let leaf = hash_vesting_leaf(&recipient, &grant);
+        require!(
+            verify_merkle_proof(leaf, &proof, leaf_index, self.schedule.merkle_root),
+            VeilProgramError::InvalidMerkleProof
+        );
+
+        let byte_index = (leaf_index / 8) as usize;
+        let bit_index = (leaf_index % 8) as u8;
+        let is_withheld = (self.schedule.withheld_bitmap[byte_index] >> bit_index) & 1 == 1;
+        require!(!is_withheld, VeilProgramError::RecipientWithheld);
+
+        let mut leaf_claim = LeafClaim::load_or_create(
+            &mut self.schedule,
+            &self.leaf_claim.to_account_info(),
+            &self.system_program.to_account_info(),
+            leaf_index,
+        )?;
+
+        // Vested so far (frozen at revocation), less what was already paid
+        let clock = Clock::get()?;
+        let vested = grant
+            .vested_amount(clock.unix_timestamp as u64)?
+            .min(leaf_claim.vested_cap.unwrap_or(u64::MAX));
+        let amount = vested.saturating_sub(leaf_claim.claimed);
+        require!(amount > 0, VeilProgramError::NothingToClaim);
+        require!(
+            amount <= self.schedule.reserved_amount,
+            VeilProgramError::InsufficientFunds
+        );
+
+        transfer_from_vault(
+            &self.vault,
+            &self.vault_ata,
+            self.recipient_ata.to_account_info(),
+            &self.token_program,
+            amount,
+        )?;
+
+        collect_fee(
+            &self.config,
+            &mut self.vault,
+            &self.vault_ata,
+            self.treasury_ata.as_ref(),
+            &self.token_program,
+            self.schedule.key(),
+            amount,
+        )?;
+        // Only keepers are tipped; recipients claiming for themselves are not
+        if self.claimer.key() == self.config.er_authority {
+            pay_keeper_tip(
+                &mut self.vault,
+                &self.claimer.to_account_info(),
+                self.schedule.key(),
+                self.schedule.keeper_tip,
+            )?;
+        }
+
+        leaf_claim.claimed = vested;
+        leaf_claim.try_serialize(&mut &mut self.leaf_claim.try_borrow_mut_data()?[..])?;
+
+        self.schedule.reserved_amount = self
+            .schedule
+            .reserved_amount
+            .checked_sub(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_sub(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(VestedClaimed {
+            schedule: self.schedule.key(),
+            recipient,
+            leaf_index,
+            amount,
+            claimed: vested,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
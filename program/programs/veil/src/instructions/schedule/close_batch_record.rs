@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::BatchRecordClosed;
+use crate::{BatchRecord, ScheduleAccount, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct CloseBatchRecord<'info> {
+    /// Anyone may close an expired record; funds only move back to the employer's vault
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    #[account(
+        mut,
+        has_one = schedule @ VeilProgramError::InvalidBatchRecord,
+        close = schedule,
+    )]
+    pub batch_record: Account<'info, BatchRecord>,
+}
+
+impl<'info> CloseBatchRecord<'info> {
+    pub fn close_batch_record(&mut self) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+
+        let clock = Clock::get()?;
+        require!(
+            clock.unix_timestamp as u64 >= self.batch_record.expires_at,
+            VeilProgramError::ClaimWindowOpen
+        );
+
+        // Unclaimed pay goes back to available
+        let released_amount = self.batch_record.held_amount;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_sub(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.available = self
+            .vault
+            .available
+            .checked_add(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        // Record rent returns to the schedule's budget when the account is closed
+        self.schedule.rent_budget = self
+            .schedule
+            .rent_budget
+            .checked_add(self.batch_record.to_account_info().lamports())
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.schedule.open_batch_records = self.schedule.open_batch_records.saturating_sub(1);
+
+        emit!(BatchRecordClosed {
+            schedule: self.schedule.key(),
+            batch: self.batch_record.batch,
+            released_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
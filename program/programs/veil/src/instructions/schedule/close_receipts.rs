@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ReceiptsClosed;
+use crate::utils::close_program_account;
+use crate::{PaymentReceipt, ScheduleAccount, VaultAccount};
+
+#[derive(Accounts)]
+pub struct CloseReceipts<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+    // remaining_accounts: PaymentReceipt PDAs of this schedule, writable
+}
+
+impl<'info> CloseReceipts<'info> {
+    /// Closes the given receipts and returns their rent to the schedule's budget.
+    pub fn close_receipts(&mut self, receipts: &[AccountInfo<'info>]) -> Result<()> {
+        let schedule_info = self.schedule.to_account_info();
+        let mut lamports: u64 = 0;
+
+        for receipt_info in receipts {
+            require!(
+                receipt_info.owner == &crate::ID && receipt_info.is_writable,
+                VeilProgramError::InvalidReceipt
+            );
+            let receipt =
+                PaymentReceipt::try_deserialize(&mut &receipt_info.try_borrow_data()?[..])?;
+            require!(
+                receipt.schedule == self.schedule.key(),
+                VeilProgramError::InvalidReceipt
+            );
+
+            let closed = close_program_account(receipt_info, &schedule_info)?;
+            lamports = lamports
+                .checked_add(closed)
+                .ok_or(VeilProgramError::InsufficientFunds)?;
+        }
+
+        self.schedule.rent_budget = self
+            .schedule
+            .rent_budget
+            .checked_add(lamports)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(ReceiptsClosed {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            count: receipts.len() as u16,
+            lamports,
+            rent_budget: self.schedule.rent_budget,
+        });
+
+        Ok(())
+    }
+}
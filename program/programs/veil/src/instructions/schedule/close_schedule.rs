@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ScheduleClosed;
+use crate::state::ScheduleStatus;
+use crate::{ScheduleAccount, VaultAccount, VaultScheduleIndex};
+
+#[derive(Accounts)]
+#[instruction(schedule_id: [u8; 32])]
+pub struct CloseSchedule<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+        realloc = VaultScheduleIndex::space(schedule_index.entries.len().saturating_sub(1)),
+        realloc::payer = employer,
+        realloc::zero = false,
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule", vault.key().as_ref(), schedule_id.as_ref()],
+        bump = schedule.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+        close = employer,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseSchedule<'info> {
+    /// Closes a finished schedule, returning its rent and rent budget to the employer.
+    pub fn close_schedule(&mut self, schedule_id: [u8; 32]) -> Result<()> {
+        require!(
+            matches!(
+                self.schedule.status,
+                ScheduleStatus::Cancelled | ScheduleStatus::Completed
+            ),
+            VeilProgramError::ScheduleNotClosable
+        );
+        // Held pay in a BatchRecord can only be released through its schedule
+        require!(
+            self.schedule.open_batch_records == 0,
+            VeilProgramError::OpenBatchRecords
+        );
+
+        self.schedule_index.remove(&self.schedule.key())?;
+
+        emit!(ScheduleClosed {
+            vault: self.vault.key(),
+            schedule: self.schedule.key(),
+            schedule_id,
+            rent_returned: self.schedule.to_account_info().lamports(),
+        });
+
+        Ok(())
+    }
+}
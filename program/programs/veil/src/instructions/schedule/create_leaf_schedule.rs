@@ -0,0 +1,217 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ScheduleCreated;
+use crate::state::{MissedPeriodPolicy, ScheduleCadence, ScheduleKind};
+use crate::utils::consume_approved_action;
+use crate::{
+    require_employer_or_operator, Operator, Organization, PendingAction, ScheduleAccount,
+    VaultAccount, VaultAction, VaultScheduleIndex, VeilConfig, ANCHOR_DISCRIMINATOR,
+    MAX_SCHEDULE_RECIPIENTS, OPERATOR_CREATE_SCHEDULE,
+};
+
+/// Shared by `create_stream_schedule` and `create_vesting_schedule`, whose leaves are
+/// claimed one by one instead of in batches.
+#[derive(Accounts)]
+#[instruction(schedule_id: [u8; 32])]
+pub struct CreateLeafSchedule<'info> {
+    /// Employer, or an operator allowed to create schedules
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ANCHOR_DISCRIMINATOR + ScheduleAccount::INIT_SPACE,
+        seeds = [b"schedule", vault.key().as_ref(), schedule_id.as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+        realloc = VaultScheduleIndex::space(schedule_index.entries.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
+    pub system_program: Program<'info, System>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> CreateLeafSchedule<'info> {
+    /// Each leaf accrues over its own time range and is claimed through `claim_stream`.
+    pub fn create_stream_schedule(
+        &mut self,
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    ) -> Result<()> {
+        self.create_leaf_schedule(
+            ScheduleKind::Stream,
+            VaultAction::CreateStreamSchedule {
+                schedule_id,
+                reserved_amount,
+                merkle_root,
+                total_recipients,
+                er_job_id,
+            },
+            schedule_id,
+            reserved_amount,
+            merkle_root,
+            total_recipients,
+            er_job_id,
+        )
+    }
+
+    /// Each leaf vests on its own grant and is claimed through `claim_vested`.
+    pub fn create_vesting_schedule(
+        &mut self,
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    ) -> Result<()> {
+        self.create_leaf_schedule(
+            ScheduleKind::Vesting,
+            VaultAction::CreateVestingSchedule {
+                schedule_id,
+                reserved_amount,
+                merkle_root,
+                total_recipients,
+                er_job_id,
+            },
+            schedule_id,
+            reserved_amount,
+            merkle_root,
+            total_recipients,
+            er_job_id,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_leaf_schedule(
+        &mut self,
+        kind: ScheduleKind,
+        action: VaultAction,
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_CREATE_SCHEDULE,
+        )?;
+        require!(reserved_amount > 0, VeilProgramError::InvalidReservedAmount);
+        require!(total_recipients > 0, VeilProgramError::InvalidMaxRecipients);
+        require!(
+            total_recipients <= self.config.max_recipients,
+            VeilProgramError::InvalidMaxRecipients
+        );
+        require!(
+            total_recipients <= MAX_SCHEDULE_RECIPIENTS,
+            VeilProgramError::InvalidMaxRecipients
+        );
+        require!(
+            reserved_amount <= self.vault.available,
+            VeilProgramError::InsufficientFunds
+        );
+
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp as u64;
+
+        // Derive bump from seeds
+        let vault_key = self.vault.key();
+        let seeds = &[b"schedule", vault_key.as_ref(), schedule_id.as_ref()];
+        let (schedule_pda, bump) =
+            Pubkey::find_program_address(seeds, self.schedule.to_account_info().owner);
+        require!(
+            schedule_pda == self.schedule.key(),
+            VeilProgramError::InvalidScheduleId
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.authority.to_account_info(),
+            action,
+        )?;
+
+        // Move funds from available to reserved
+        self.vault.available = self
+            .vault
+            .available
+            .checked_sub(reserved_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_add(reserved_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        // No batches: leaves are paid individually as they accrue or vest
+        self.schedule.set_inner(ScheduleAccount::init(
+            self.vault.employer,
+            self.vault.key(),
+            ScheduleCadence {
+                kind,
+                interval_secs: 0,
+                next_execution: current_time,
+                missed_period_policy: MissedPeriodPolicy::Skip,
+                end_at: None,
+                per_execution_amount: 0,
+                max_executions: None,
+            },
+            reserved_amount,
+            merkle_root,
+            total_recipients,
+            er_job_id,
+            bump,
+        ));
+        self.schedule_index.add(schedule_id, self.schedule.key());
+
+        emit!(ScheduleCreated {
+            employer: self.vault.employer,
+            vault: self.vault.key(),
+            schedule: self.schedule.key(),
+            reserved_amount,
+            interval_secs: 0,
+            next_execution: current_time as i64,
+        });
+
+        Ok(())
+    }
+}
@@ -2,17 +2,21 @@ use anchor_lang::prelude::*;
 
 use crate::error::VeilProgramError;
 use crate::events::ScheduleCreated;
-use crate::state::ScheduleStatus;
+use crate::state::{MissedPeriodPolicy, ScheduleCadence, ScheduleKind};
+use crate::utils::consume_approved_action;
 use crate::{
-    ScheduleAccount, VaultAccount, VeilConfig, ANCHOR_DISCRIMINATOR,
-    MAX_SCHEDULE_INTERVAL_SECS, MAX_SCHEDULE_RECIPIENTS, MIN_SCHEDULE_INTERVAL_SECS,
+    first_execution_time, require_employer_or_operator, Operator, Organization, PendingAction,
+    ScheduleAccount, VaultAccount, VaultAction, VaultScheduleIndex, VeilConfig,
+    ANCHOR_DISCRIMINATOR, MAX_SCHEDULE_INTERVAL_SECS, MAX_SCHEDULE_RECIPIENTS,
+    MIN_SCHEDULE_INTERVAL_SECS, OPERATOR_CREATE_SCHEDULE,
 };
 
 #[derive(Accounts)]
 #[instruction(schedule_id: [u8; 32])]
 pub struct CreateSchedule<'info> {
+    /// Employer, or an operator allowed to create schedules
     #[account(mut)]
-    pub employer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         seeds = [b"veil_config"],
@@ -22,25 +26,44 @@ pub struct CreateSchedule<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", employer.key().as_ref(), vault.token_mint.as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
         bump = vault.bump,
-        has_one = employer @ VeilProgramError::Unauthorized,
     )]
     pub vault: Account<'info, VaultAccount>,
 
     #[account(
         init,
-        payer = employer,
+        payer = authority,
         space = ANCHOR_DISCRIMINATOR + ScheduleAccount::INIT_SPACE,
         seeds = [b"schedule", vault.key().as_ref(), schedule_id.as_ref()],
         bump
     )]
     pub schedule: Account<'info, ScheduleAccount>,
 
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+        realloc = VaultScheduleIndex::space(schedule_index.entries.len() + 1),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
     pub system_program: Program<'info, System>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
 }
 
 impl<'info> CreateSchedule<'info> {
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::manual_range_contains)]
     pub fn create_schedule(
         &mut self,
         schedule_id: [u8; 32],
@@ -50,8 +73,20 @@ impl<'info> CreateSchedule<'info> {
         merkle_root: [u8; 32],
         total_recipients: u16,
         er_job_id: [u8; 32],
+        missed_period_policy: MissedPeriodPolicy,
+        start_at: Option<u64>,
+        end_at: Option<u64>,
+        max_executions: Option<u64>,
     ) -> Result<()> {
         require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_CREATE_SCHEDULE,
+        )?;
         require!(
             interval_secs >= MIN_SCHEDULE_INTERVAL_SECS
                 && interval_secs <= MAX_SCHEDULE_INTERVAL_SECS,
@@ -79,9 +114,14 @@ impl<'info> CreateSchedule<'info> {
             per_execution_amount <= reserved_amount,
             VeilProgramError::InvalidPerExecutionAmount
         );
+        require!(
+            max_executions != Some(0),
+            VeilProgramError::InvalidMaxExecutions
+        );
 
         let clock = Clock::get()?;
-        let next_execution = clock.unix_timestamp as u64 + interval_secs;
+        let next_execution =
+            first_execution_time(clock.unix_timestamp as u64, interval_secs, start_at, end_at)?;
 
         // Derive bump from seeds
         let vault_key = self.vault.key();
@@ -93,6 +133,25 @@ impl<'info> CreateSchedule<'info> {
             VeilProgramError::InvalidScheduleId
         );
 
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.authority.to_account_info(),
+            VaultAction::CreateSchedule {
+                schedule_id,
+                interval_secs,
+                reserved_amount,
+                per_execution_amount,
+                merkle_root,
+                total_recipients,
+                er_job_id,
+                missed_period_policy,
+                start_at,
+                end_at,
+                max_executions,
+            },
+        )?;
+
         // Move funds from available to reserved
         self.vault.available = self
             .vault
@@ -106,26 +165,28 @@ impl<'info> CreateSchedule<'info> {
             .ok_or(VeilProgramError::InsufficientFunds)?;
 
         // Initialize schedule
-        self.schedule.set_inner(ScheduleAccount {
-            employer: self.employer.key(),
-            vault: self.vault.key(),
-            status: ScheduleStatus::Active,
-            interval_secs,
-            next_execution,
+        self.schedule.set_inner(ScheduleAccount::init(
+            self.vault.employer,
+            self.vault.key(),
+            ScheduleCadence {
+                kind: ScheduleKind::Interval,
+                interval_secs,
+                next_execution,
+                missed_period_policy,
+                end_at,
+                per_execution_amount,
+                max_executions,
+            },
             reserved_amount,
-            per_execution_amount,
-            er_job_id,
             merkle_root,
             total_recipients,
-            paid_count: 0,
-            paid_bitmap: [0u8; 128],
-            last_executed_batch: 0,
-            batch_start_time: 0, // Will be set on first claim
+            er_job_id,
             bump,
-        });
+        ));
+        self.schedule_index.add(schedule_id, self.schedule.key());
 
         emit!(ScheduleCreated {
-            employer: self.employer.key(),
+            employer: self.vault.employer,
             vault: self.vault.key(),
             schedule: self.schedule.key(),
             reserved_amount,
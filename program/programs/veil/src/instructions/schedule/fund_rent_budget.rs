@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::error::VeilProgramError;
+use crate::events::{RentBudgetFunded, RentBudgetWithdrawn};
+use crate::{ScheduleAccount, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct FundRentBudget<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundRentBudget<'info> {
+    pub fn fund_rent_budget(&mut self, lamports: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(lamports > 0, VeilProgramError::InsufficientFunds);
+
+        let cpi_accounts = Transfer {
+            from: self.employer.to_account_info(),
+            to: self.schedule.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, lamports)?;
+
+        self.schedule.rent_budget = self
+            .schedule
+            .rent_budget
+            .checked_add(lamports)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(RentBudgetFunded {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            lamports,
+            rent_budget: self.schedule.rent_budget,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_rent_budget(&mut self, lamports: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(lamports > 0, VeilProgramError::InsufficientFunds);
+
+        self.schedule.rent_budget = self
+            .schedule
+            .rent_budget
+            .checked_sub(lamports)
+            .ok_or(VeilProgramError::InsufficientRentBudget)?;
+
+        // The schedule is program-owned, so its lamports can be debited directly
+        let schedule_info = self.schedule.to_account_info();
+        **schedule_info.try_borrow_mut_lamports()? -= lamports;
+        **self.employer.to_account_info().try_borrow_mut_lamports()? += lamports;
+
+        emit!(RentBudgetWithdrawn {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            lamports,
+            rent_budget: self.schedule.rent_budget,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::{ScheduleDefunded, ScheduleFunded};
+use crate::state::{ScheduleKind, ScheduleStatus};
+use crate::utils::consume_approved_action;
+use crate::{PendingAction, ScheduleAccount, VaultAccount, VaultAction, VeilConfig};
+
+#[derive(Accounts)]
+pub struct FundSchedule<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> FundSchedule<'info> {
+    pub fn fund_schedule(&mut self, amount: u64) -> Result<()> {
+        self.validate_reserve_change(amount)?;
+        require!(
+            amount <= self.vault.available,
+            VeilProgramError::InsufficientFunds
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::FundSchedule {
+                schedule: self.schedule.key(),
+                amount,
+            },
+        )?;
+
+        // Move funds from available to the schedule's reserve
+        self.vault.available = self
+            .vault
+            .available
+            .checked_sub(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_add(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.schedule.reserved_amount = self
+            .schedule
+            .reserved_amount
+            .checked_add(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(ScheduleFunded {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            amount,
+            reserved_amount: self.schedule.reserved_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    pub fn defund_schedule(&mut self, amount: u64) -> Result<()> {
+        self.validate_reserve_change(amount)?;
+
+        // The unpaid rest of the current cycle stays funded; only future cycles can be pulled back
+        let current_cycle = self
+            .schedule
+            .per_execution_amount
+            .saturating_sub(self.schedule.batch_paid_amount);
+        let unreserved = self.schedule.reserved_amount.saturating_sub(current_cycle);
+        require!(amount <= unreserved, VeilProgramError::ExceedsFutureCycles);
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::DefundSchedule {
+                schedule: self.schedule.key(),
+                amount,
+            },
+        )?;
+
+        self.schedule.reserved_amount = self
+            .schedule
+            .reserved_amount
+            .checked_sub(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_sub(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.available = self
+            .vault
+            .available
+            .checked_add(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(ScheduleDefunded {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            amount,
+            reserved_amount: self.schedule.reserved_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    fn validate_reserve_change(&self, amount: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            self.schedule.kind == ScheduleKind::Interval,
+            VeilProgramError::InvalidScheduleKind
+        );
+        require!(amount > 0, VeilProgramError::InvalidReservedAmount);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,57 @@
 pub mod cancel_schedule;
+pub mod claim_past_batch;
 pub mod claim_payment;
+pub mod claim_stream;
+pub mod claim_vested;
+pub mod close_batch_record;
+pub mod close_receipts;
+pub mod close_schedule;
+pub mod create_leaf_schedule;
+pub mod create_one_shot_schedule;
 pub mod create_schedule;
 pub mod delegate_schedule;
+pub mod fund_rent_budget;
+pub mod fund_schedule;
 pub mod pause_schedule;
-pub mod update_schedule;
+pub mod revoke_vesting;
+pub mod set_auto_refill;
+pub mod set_claim_window;
+pub mod set_keeper_tip;
+pub mod set_payout_mode;
+pub mod set_receipt_mode;
+pub mod settle_batch;
+pub mod stage_schedule_update;
+pub mod sync_schedule_employer;
+pub mod sync_schedule_index;
 pub mod undelegate_schedule;
+pub mod update_schedule;
+pub mod withhold_recipient;
 
 pub use cancel_schedule::*;
+pub use claim_past_batch::*;
 pub use claim_payment::*;
+pub use claim_stream::*;
+pub use claim_vested::*;
+pub use close_batch_record::*;
+pub use close_receipts::*;
+pub use close_schedule::*;
+pub use create_leaf_schedule::*;
+pub use create_one_shot_schedule::*;
 pub use create_schedule::*;
 pub use delegate_schedule::*;
+pub use fund_rent_budget::*;
+pub use fund_schedule::*;
 pub use pause_schedule::*;
-pub use update_schedule::*;
+pub use revoke_vesting::*;
+pub use set_auto_refill::*;
+pub use set_claim_window::*;
+pub use set_keeper_tip::*;
+pub use set_payout_mode::*;
+pub use set_receipt_mode::*;
+pub use settle_batch::*;
+pub use stage_schedule_update::*;
+pub use sync_schedule_employer::*;
+pub use sync_schedule_index::*;
 pub use undelegate_schedule::*;
+pub use update_schedule::*;
+pub use withhold_recipient::*;
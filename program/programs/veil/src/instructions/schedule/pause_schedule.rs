@@ -3,12 +3,16 @@ use anchor_lang::prelude::*;
 use crate::error::VeilProgramError;
 use crate::events::{SchedulePaused, ScheduleResumed};
 use crate::state::ScheduleStatus;
-use crate::{ScheduleAccount, VeilConfig};
+use crate::{
+    require_employer_or_operator, Operator, Organization, ScheduleAccount, VaultAccount,
+    VaultScheduleIndex, VeilConfig, OPERATOR_PAUSE_SCHEDULE,
+};
 
 #[derive(Accounts)]
 pub struct PauseSchedule<'info> {
+    /// Employer, or an operator allowed to pause schedules
     #[account(mut)]
-    pub employer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         seeds = [b"veil_config"],
@@ -16,16 +20,41 @@ pub struct PauseSchedule<'info> {
     )]
     pub config: Account<'info, VeilConfig>,
 
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
     #[account(
         mut,
-        has_one = employer @ VeilProgramError::Unauthorized,
+        has_one = vault @ VeilProgramError::VaultMismatch,
     )]
     pub schedule: Account<'info, ScheduleAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
 }
 
 impl<'info> PauseSchedule<'info> {
     pub fn pause_schedule(&mut self, pause: bool) -> Result<()> {
         require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_PAUSE_SCHEDULE,
+        )?;
 
         let clock = Clock::get()?;
         let timestamp = clock.unix_timestamp;
@@ -36,9 +65,11 @@ impl<'info> PauseSchedule<'info> {
                 VeilProgramError::ScheduleNotActive
             );
             self.schedule.status = ScheduleStatus::Paused;
+            self.schedule_index
+                .set_status(&self.schedule.key(), ScheduleStatus::Paused)?;
 
             emit!(SchedulePaused {
-                employer: self.employer.key(),
+                employer: self.vault.employer,
                 schedule: self.schedule.key(),
                 timestamp,
             });
@@ -48,9 +79,11 @@ impl<'info> PauseSchedule<'info> {
                 VeilProgramError::ScheduleNotPaused
             );
             self.schedule.status = ScheduleStatus::Active;
+            self.schedule_index
+                .set_status(&self.schedule.key(), ScheduleStatus::Active)?;
 
             emit!(ScheduleResumed {
-                employer: self.employer.key(),
+                employer: self.vault.employer,
                 schedule: self.schedule.key(),
                 timestamp,
             });
@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::VestingRevoked;
+use crate::state::{ScheduleKind, ScheduleStatus, VestingGrant};
+use crate::utils::consume_approved_action;
+use crate::utils::merkle::{hash_vesting_leaf, verify_merkle_proof};
+use crate::{
+    require_employer_or_operator, LeafClaim, Operator, Organization, PendingAction,
+    ScheduleAccount, VaultAccount, VaultAction, VeilConfig, OPERATOR_CANCEL,
+};
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    /// Employer, or an operator allowed to cancel schedules
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    /// CHECK: LeafClaim PDA for this leaf, created from the schedule's rent budget if missing
+    #[account(mut)]
+    pub leaf_claim: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> RevokeVesting<'info> {
+    /// Freezes a leaf at what has vested so far and returns the rest to `vault.available`.
+    pub fn revoke_vesting(
+        &mut self,
+        recipient: Pubkey,
+        grant: VestingGrant,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_CANCEL,
+        )?;
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            self.schedule.kind == ScheduleKind::Vesting,
+            VeilProgramError::InvalidScheduleKind
+        );
+        require!(
+            leaf_index < self.schedule.total_recipients,
+            VeilProgramError::InvalidLeafIndex
+        );
+        grant.validate()?;
+
+        let leaf = hash_vesting_leaf(&recipient, &grant);
+        require!(
+            verify_merkle_proof(leaf, &proof, leaf_index, self.schedule.merkle_root),
+            VeilProgramError::InvalidMerkleProof
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.authority.to_account_info(),
+            VaultAction::RevokeVesting {
+                schedule: self.schedule.key(),
+                leaf_index,
+            },
+        )?;
+
+        let mut leaf_claim = LeafClaim::load_or_create(
+            &mut self.schedule,
+            &self.leaf_claim.to_account_info(),
+            &self.system_program.to_account_info(),
+            leaf_index,
+        )?;
+        require!(
+            leaf_claim.vested_cap.is_none(),
+            VeilProgramError::VestingAlreadyRevoked
+        );
+
+        let clock = Clock::get()?;
+        let vested_amount = grant.vested_amount(clock.unix_timestamp as u64)?;
+        let released_amount = grant
+            .total
+            .saturating_sub(vested_amount)
+            .min(self.schedule.reserved_amount);
+
+        leaf_claim.vested_cap = Some(vested_amount);
+        leaf_claim.try_serialize(&mut &mut self.leaf_claim.try_borrow_mut_data()?[..])?;
+
+        // Move the unvested part back from reserved to available
+        self.schedule.reserved_amount = self
+            .schedule
+            .reserved_amount
+            .checked_sub(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.reserved = self
+            .vault
+            .reserved
+            .checked_sub(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        self.vault.available = self
+            .vault
+            .available
+            .checked_add(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(VestingRevoked {
+            employer: self.vault.employer,
+            schedule: self.schedule.key(),
+            leaf_index,
+            vested_amount,
+            released_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::AutoRefillUpdated;
+use crate::state::{ScheduleKind, ScheduleStatus};
+use crate::{ScheduleAccount, VaultAccount, VeilConfig, MAX_AUTO_REFILL_CYCLES};
+
+#[derive(Accounts)]
+pub struct SetAutoRefill<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+}
+
+impl<'info> SetAutoRefill<'info> {
+    pub fn set_auto_refill(&mut self, target_cycles: u16) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            self.schedule.kind == ScheduleKind::Interval,
+            VeilProgramError::InvalidScheduleKind
+        );
+        require!(
+            target_cycles <= MAX_AUTO_REFILL_CYCLES,
+            VeilProgramError::InvalidAutoRefillCycles
+        );
+
+        self.schedule.auto_refill_cycles = target_cycles;
+
+        emit!(AutoRefillUpdated {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            target_cycles,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
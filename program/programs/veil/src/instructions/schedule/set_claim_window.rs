@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ClaimWindowUpdated;
+use crate::state::ScheduleStatus;
+use crate::{ScheduleAccount, VaultAccount, VeilConfig, MAX_CLAIM_WINDOW_SECS};
+
+#[derive(Accounts)]
+pub struct SetClaimWindow<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+}
+
+impl<'info> SetClaimWindow<'info> {
+    pub fn set_claim_window(&mut self, claim_window_secs: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            claim_window_secs <= MAX_CLAIM_WINDOW_SECS,
+            VeilProgramError::InvalidClaimWindow
+        );
+
+        self.schedule.claim_window_secs = claim_window_secs;
+
+        emit!(ClaimWindowUpdated {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            claim_window_secs,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
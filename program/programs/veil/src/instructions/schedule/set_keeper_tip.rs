@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::KeeperTipUpdated;
+use crate::state::ScheduleStatus;
+use crate::{ScheduleAccount, VaultAccount, VeilConfig, MAX_KEEPER_TIP_LAMPORTS};
+
+#[derive(Accounts)]
+pub struct SetKeeperTip<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+}
+
+impl<'info> SetKeeperTip<'info> {
+    pub fn set_keeper_tip(&mut self, keeper_tip: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            keeper_tip <= MAX_KEEPER_TIP_LAMPORTS,
+            VeilProgramError::InvalidKeeperTip
+        );
+
+        self.schedule.keeper_tip = keeper_tip;
+
+        emit!(KeeperTipUpdated {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            keeper_tip,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::PayoutModeUpdated;
+use crate::state::{PayoutMode, ScheduleStatus};
+use crate::utils::consume_approved_action;
+use crate::{PendingAction, ScheduleAccount, VaultAccount, VaultAction, VeilConfig};
+
+#[derive(Accounts)]
+pub struct SetPayoutMode<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> SetPayoutMode<'info> {
+    /// Switches how leaf values are read. The root has to change with it, so this is
+    /// only allowed while paused between batches, ahead of `update_schedule`.
+    pub fn set_payout_mode(&mut self, payout_mode: PayoutMode) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotPaused
+        );
+        require!(
+            self.schedule.paid_count == 0 && self.schedule.batch_start_time == 0,
+            VeilProgramError::ScheduleBatchInProgress
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::SetPayoutMode {
+                schedule: self.schedule.key(),
+                payout_mode,
+            },
+        )?;
+
+        self.schedule.payout_mode = payout_mode;
+
+        emit!(PayoutModeUpdated {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            payout_mode,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ReceiptModeUpdated;
+use crate::state::ScheduleStatus;
+use crate::{ScheduleAccount, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct SetReceiptMode<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+}
+
+impl<'info> SetReceiptMode<'info> {
+    pub fn set_receipt_mode(&mut self, enabled: bool) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+
+        self.schedule.receipt_mode = enabled;
+
+        emit!(ReceiptModeUpdated {
+            employer: self.employer.key(),
+            schedule: self.schedule.key(),
+            enabled,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::BatchSettled;
+use crate::state::{ScheduleKind, ScheduleStatus};
+use crate::{utils, ScheduleAccount, VaultAccount, VaultScheduleIndex, VeilConfig};
+
+#[derive(Accounts)]
+#[instruction(schedule_id: [u8; 32])]
+pub struct SettleBatch<'info> {
+    /// Anyone may settle a batch that no claim will close
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule", vault.key().as_ref(), schedule_id.as_ref()],
+        bump = schedule.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    /// CHECK: Batch record PDA for the current batch, created from the schedule's rent
+    /// budget only if the batch closes with unclaimed pay and a claim window is set
+    #[account(mut)]
+    pub batch_record: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    /// Marked completed if this settlement finishes the schedule; the index isn't delegated,
+    /// so it is left out in the rollup and caught up through `sync_schedule_index`
+    #[account(
+        mut,
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump = schedule_index.bump,
+    )]
+    pub schedule_index: Option<Account<'info, VaultScheduleIndex>>,
+}
+
+impl<'info> SettleBatch<'info> {
+    /// Closes a due batch that no claim will settle: its remaining leaves are all withheld,
+    /// it has been open past the batch timeout, or the schedule's end time has passed. A
+    /// schedule that has nothing left to run completes here and releases its reserve.
+    pub fn settle_batch(&mut self, _schedule_id: [u8; 32]) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            matches!(
+                self.schedule.kind,
+                ScheduleKind::Interval | ScheduleKind::OneShot
+            ),
+            VeilProgramError::InvalidScheduleKind
+        );
+
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp as u64;
+        require!(
+            current_time >= self.schedule.next_execution,
+            VeilProgramError::ExecutionTooEarly
+        );
+        require!(
+            self.schedule.settled_leaf_count() >= self.schedule.total_recipients
+                || self
+                    .schedule
+                    .batch_timed_out(current_time, self.config.batch_timeout_secs)
+                || self.schedule.has_ended(current_time),
+            VeilProgramError::BatchNotSettleable
+        );
+
+        let paid_amount = self.schedule.batch_paid_amount;
+        utils::settle_batch(
+            &mut self.schedule,
+            &mut self.vault,
+            self.schedule_index.as_mut(),
+            self.batch_record.as_ref(),
+            self.system_program.as_ref(),
+            current_time,
+            clock.unix_timestamp,
+        )?;
+
+        emit!(BatchSettled {
+            schedule: self.schedule.key(),
+            settler: self.caller.key(),
+            batch: self.schedule.last_executed_batch,
+            paid_amount,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
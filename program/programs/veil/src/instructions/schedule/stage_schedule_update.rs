@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ScheduleUpdateStaged;
+use crate::state::{ScheduleKind, ScheduleStatus};
+use crate::utils::consume_approved_action;
+use crate::{
+    require_employer_or_operator, Operator, Organization, PendingAction, ScheduleAccount,
+    VaultAccount, VaultAction, VeilConfig, MAX_SCHEDULE_RECIPIENTS, OPERATOR_UPDATE_SCHEDULE,
+};
+
+#[derive(Accounts)]
+pub struct StageScheduleUpdate<'info> {
+    /// Employer, or an operator allowed to update schedules
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> StageScheduleUpdate<'info> {
+    pub fn stage_schedule_update(
+        &mut self,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        per_execution_amount: u64,
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_UPDATE_SCHEDULE,
+        )?;
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            self.schedule.kind == ScheduleKind::Interval,
+            VeilProgramError::InvalidScheduleKind
+        );
+        require!(
+            per_execution_amount > 0,
+            VeilProgramError::InvalidPerExecutionAmount
+        );
+        require!(
+            per_execution_amount <= self.schedule.reserved_amount,
+            VeilProgramError::InvalidPerExecutionAmount
+        );
+        require!(total_recipients > 0, VeilProgramError::InvalidMaxRecipients);
+        require!(
+            total_recipients <= self.config.max_recipients,
+            VeilProgramError::InvalidMaxRecipients
+        );
+        require!(
+            total_recipients <= MAX_SCHEDULE_RECIPIENTS,
+            VeilProgramError::InvalidMaxRecipients
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.authority.to_account_info(),
+            VaultAction::StageScheduleUpdate {
+                schedule: self.schedule.key(),
+                merkle_root,
+                total_recipients,
+                per_execution_amount,
+            },
+        )?;
+
+        // Applied by claim_payment when the current batch closes (or the next one opens)
+        self.schedule.pending_merkle_root = Some(merkle_root);
+        self.schedule.pending_total_recipients = total_recipients;
+        self.schedule.pending_per_execution_amount = per_execution_amount;
+
+        emit!(ScheduleUpdateStaged {
+            employer: self.vault.employer,
+            schedule: self.schedule.key(),
+            merkle_root,
+            total_recipients,
+            per_execution_amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ScheduleEmployerSynced;
+use crate::{ScheduleAccount, VaultAccount};
+
+#[derive(Accounts)]
+pub struct SyncScheduleEmployer<'info> {
+    /// Anyone may sync; the employer is only ever copied from the vault
+    pub caller: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+}
+
+impl<'info> SyncScheduleEmployer<'info> {
+    /// Copies the vault's current employer onto a schedule missed by `accept_vault_ownership`.
+    pub fn sync_schedule_employer(&mut self) -> Result<()> {
+        self.schedule.employer = self.vault.employer;
+
+        emit!(ScheduleEmployerSynced {
+            vault: self.vault.key(),
+            schedule: self.schedule.key(),
+            employer: self.vault.employer,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ScheduleIndexSynced;
+use crate::{ScheduleAccount, VaultScheduleIndex};
+
+#[derive(Accounts)]
+pub struct SyncScheduleIndex<'info> {
+    /// Anyone may sync; the status is only ever copied from the schedule
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"schedule_index", schedule.vault.as_ref()],
+        bump = schedule_index.bump,
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
+    pub schedule: Account<'info, ScheduleAccount>,
+}
+
+impl<'info> SyncScheduleIndex<'info> {
+    /// Copies a schedule's status into the index, e.g. after it completed in the rollup
+    /// and has been undelegated back to the base layer.
+    pub fn sync_schedule_index(&mut self) -> Result<()> {
+        let status = self.schedule.status.clone();
+        self.schedule_index
+            .set_status(&self.schedule.key(), status.clone())?;
+
+        emit!(ScheduleIndexSynced {
+            vault: self.schedule.vault,
+            schedule: self.schedule.key(),
+            status,
+        });
+
+        Ok(())
+    }
+}
@@ -2,16 +2,19 @@ use anchor_lang::prelude::*;
 
 use crate::error::VeilProgramError;
 use crate::events::ScheduleUpdated;
-use crate::state::ScheduleStatus;
+use crate::state::{MissedPeriodPolicy, ScheduleKind, ScheduleStatus};
+use crate::utils::consume_approved_action;
 use crate::{
-    MAX_SCHEDULE_INTERVAL_SECS, MAX_SCHEDULE_RECIPIENTS, MIN_SCHEDULE_INTERVAL_SECS,
-    ScheduleAccount, VaultAccount, VeilConfig,
+    first_execution_time, require_employer_or_operator, Operator, Organization, PendingAction,
+    ScheduleAccount, VaultAccount, VaultAction, VeilConfig, MAX_SCHEDULE_INTERVAL_SECS,
+    MAX_SCHEDULE_RECIPIENTS, MIN_SCHEDULE_INTERVAL_SECS, OPERATOR_UPDATE_SCHEDULE,
 };
 
 #[derive(Accounts)]
 pub struct UpdateSchedule<'info> {
+    /// Employer, or an operator allowed to update schedules
     #[account(mut)]
-    pub employer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         seeds = [b"veil_config"],
@@ -19,21 +22,27 @@ pub struct UpdateSchedule<'info> {
     )]
     pub config: Account<'info, VeilConfig>,
 
-    #[account(
-        mut,
-        has_one = employer @ VeilProgramError::Unauthorized,
-    )]
+    #[account(mut)]
     pub vault: Account<'info, VaultAccount>,
 
     #[account(
         mut,
-        has_one = employer @ VeilProgramError::Unauthorized,
         has_one = vault @ VeilProgramError::VaultMismatch,
     )]
     pub schedule: Account<'info, ScheduleAccount>,
+
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
 }
 
 impl<'info> UpdateSchedule<'info> {
+    #[allow(clippy::too_many_arguments)]
+    #[allow(clippy::manual_range_contains)]
     pub fn update_schedule(
         &mut self,
         interval_secs: u64,
@@ -41,8 +50,24 @@ impl<'info> UpdateSchedule<'info> {
         per_execution_amount: u64,
         merkle_root: [u8; 32],
         total_recipients: u16,
+        missed_period_policy: MissedPeriodPolicy,
+        start_at: Option<u64>,
+        end_at: Option<u64>,
+        max_executions: Option<u64>,
     ) -> Result<()> {
         require!(!self.config.paused, VeilProgramError::Paused);
+        require_employer_or_operator(
+            &self.vault.employer,
+            &self.vault.key(),
+            &self.authority.key(),
+            self.operator.as_ref(),
+            self.organization.as_ref(),
+            OPERATOR_UPDATE_SCHEDULE,
+        )?;
+        require!(
+            self.schedule.kind == ScheduleKind::Interval,
+            VeilProgramError::InvalidScheduleKind
+        );
         require!(
             self.schedule.status == ScheduleStatus::Paused,
             VeilProgramError::ScheduleNotPaused
@@ -74,6 +99,29 @@ impl<'info> UpdateSchedule<'info> {
             per_execution_amount <= reserved_amount,
             VeilProgramError::InvalidPerExecutionAmount
         );
+        let executed_batches = self.schedule.last_executed_batch;
+        require!(
+            max_executions.is_none_or(|max_executions| max_executions > executed_batches),
+            VeilProgramError::InvalidMaxExecutions
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.authority.to_account_info(),
+            VaultAction::UpdateSchedule {
+                schedule: self.schedule.key(),
+                interval_secs,
+                reserved_amount,
+                per_execution_amount,
+                merkle_root,
+                total_recipients,
+                missed_period_policy,
+                start_at,
+                end_at,
+                max_executions,
+            },
+        )?;
 
         if reserved_amount > self.schedule.reserved_amount {
             let additional_reserve = reserved_amount
@@ -114,20 +162,32 @@ impl<'info> UpdateSchedule<'info> {
         }
 
         let clock = Clock::get()?;
-        let next_execution = clock.unix_timestamp as u64 + interval_secs;
+        let next_execution =
+            first_execution_time(clock.unix_timestamp as u64, interval_secs, start_at, end_at)?;
 
         self.schedule.interval_secs = interval_secs;
         self.schedule.next_execution = next_execution;
+        self.schedule.anchor_time = next_execution;
+        self.schedule.missed_period_policy = missed_period_policy;
+        self.schedule.end_at = end_at;
+        self.schedule.max_executions = max_executions;
         self.schedule.reserved_amount = reserved_amount;
         self.schedule.per_execution_amount = per_execution_amount;
         self.schedule.merkle_root = merkle_root;
         self.schedule.total_recipients = total_recipients;
         self.schedule.paid_count = 0;
         self.schedule.paid_bitmap = [0u8; 128];
+        self.schedule.withheld_bitmap = [0u8; 128];
+        self.schedule.batch_paid_amount = 0;
+        self.schedule.batch_paid_bps = 0;
+        self.schedule.batch_dust = 0;
+        self.schedule.pending_merkle_root = None;
+        self.schedule.pending_total_recipients = 0;
+        self.schedule.pending_per_execution_amount = 0;
         self.schedule.batch_start_time = 0;
 
         emit!(ScheduleUpdated {
-            employer: self.employer.key(),
+            employer: self.vault.employer,
             schedule: self.schedule.key(),
             reserved_amount,
             per_execution_amount,
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::{RecipientReleased, RecipientWithheld};
+use crate::state::ScheduleStatus;
+use crate::utils::consume_approved_action;
+use crate::{PendingAction, ScheduleAccount, VaultAccount, VaultAction, VeilConfig};
+
+#[derive(Accounts)]
+pub struct WithholdRecipient<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub schedule: Account<'info, ScheduleAccount>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> WithholdRecipient<'info> {
+    pub fn withhold_recipient(&mut self, leaf_index: u16, withhold: bool) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.schedule.status == ScheduleStatus::Active
+                || self.schedule.status == ScheduleStatus::Paused,
+            VeilProgramError::ScheduleNotActive
+        );
+        require!(
+            leaf_index < self.schedule.total_recipients,
+            VeilProgramError::InvalidLeafIndex
+        );
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::WithholdRecipient {
+                schedule: self.schedule.key(),
+                leaf_index,
+                withhold,
+            },
+        )?;
+
+        let byte_index = (leaf_index / 8) as usize;
+        let bit_index = (leaf_index % 8) as u8;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        if withhold {
+            self.schedule.withheld_bitmap[byte_index] |= 1 << bit_index;
+
+            emit!(RecipientWithheld {
+                employer: self.employer.key(),
+                schedule: self.schedule.key(),
+                leaf_index,
+                timestamp,
+            });
+        } else {
+            self.schedule.withheld_bitmap[byte_index] &= !(1 << bit_index);
+
+            emit!(RecipientReleased {
+                employer: self.employer.key(),
+                schedule: self.schedule.key(),
+                leaf_index,
+                timestamp,
+            });
+        }
+
+        Ok(())
+    }
+}
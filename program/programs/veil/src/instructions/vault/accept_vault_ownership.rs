@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::VaultOwnershipTransferred;
+use crate::{ScheduleAccount, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct AcceptVaultOwnership<'info> {
+    pub new_employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+    // remaining_accounts: ScheduleAccounts of this vault to re-point, writable
+}
+
+impl<'info> AcceptVaultOwnership<'info> {
+    /// Hands the vault to the pending employer and updates `employer` on the given schedules.
+    /// Schedules left out (e.g. delegated ones) can be caught up with `sync_schedule_employer`.
+    pub fn accept_vault_ownership(&mut self, schedules: &[AccountInfo<'info>]) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.vault.pending_employer == Some(self.new_employer.key()),
+            VeilProgramError::NoPendingOwnershipTransfer
+        );
+
+        let previous_employer = self.vault.employer;
+        let new_employer = self.new_employer.key();
+        self.vault.employer = new_employer;
+        self.vault.pending_employer = None;
+
+        for schedule_info in schedules {
+            require!(
+                schedule_info.owner == &crate::ID && schedule_info.is_writable,
+                VeilProgramError::VaultMismatch
+            );
+            let mut schedule =
+                ScheduleAccount::try_deserialize(&mut &schedule_info.try_borrow_data()?[..])?;
+            require!(
+                schedule.vault == self.vault.key(),
+                VeilProgramError::VaultMismatch
+            );
+
+            schedule.employer = new_employer;
+            schedule.try_serialize(&mut &mut schedule_info.try_borrow_mut_data()?[..])?;
+        }
+
+        emit!(VaultOwnershipTransferred {
+            vault: self.vault.key(),
+            previous_employer,
+            new_employer,
+            schedules_updated: schedules.len() as u16,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::OperatorAdded;
+use crate::{Operator, VaultAccount, VeilConfig, ANCHOR_DISCRIMINATOR, OPERATOR_ALL_PERMISSIONS};
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct AddOperator<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        init,
+        payer = employer,
+        space = ANCHOR_DISCRIMINATOR + Operator::INIT_SPACE,
+        seeds = [b"operator", vault.key().as_ref(), operator.as_ref()],
+        bump
+    )]
+    pub operator_account: Account<'info, Operator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddOperator<'info> {
+    pub fn add_operator(&mut self, operator: Pubkey, permissions: u8) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            permissions != 0 && permissions & !OPERATOR_ALL_PERMISSIONS == 0,
+            VeilProgramError::InvalidOperatorPermissions
+        );
+        require!(
+            operator != Pubkey::default() && operator != self.employer.key(),
+            VeilProgramError::InvalidOperatorPermissions
+        );
+
+        let vault_key = self.vault.key();
+        let seeds = &[b"operator", vault_key.as_ref(), operator.as_ref()];
+        let (_, bump) =
+            Pubkey::find_program_address(seeds, self.operator_account.to_account_info().owner);
+
+        self.operator_account.set_inner(Operator {
+            vault: vault_key,
+            operator,
+            permissions,
+            bump,
+        });
+
+        emit!(OperatorAdded {
+            vault: vault_key,
+            employer: self.employer.key(),
+            operator,
+            permissions,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ActionApproved;
+use crate::{PendingAction, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct ApproveAction<'info> {
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_action", vault.key().as_ref(), pending_action.nonce.to_le_bytes().as_ref()],
+        bump = pending_action.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+}
+
+impl<'info> ApproveAction<'info> {
+    pub fn approve_action(&mut self) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+
+        let approver = self.approver.key();
+        require!(
+            self.vault.approvers.contains(&approver),
+            VeilProgramError::NotAnApprover
+        );
+        require!(
+            !self.pending_action.approvals.contains(&approver),
+            VeilProgramError::AlreadyApproved
+        );
+
+        // Approvers removed since proposing are dropped so the list stays within max_len
+        let vault = &self.vault;
+        self.pending_action
+            .approvals
+            .retain(|existing| vault.approvers.contains(existing));
+        self.pending_action.approvals.push(approver);
+
+        emit!(ActionApproved {
+            vault: self.vault.key(),
+            pending_action: self.pending_action.key(),
+            approver,
+            approvals: self.pending_action.approval_count(&self.vault),
+        });
+
+        Ok(())
+    }
+}
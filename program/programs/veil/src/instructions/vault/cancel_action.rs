@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ActionCancelled;
+use crate::{PendingAction, VaultAccount};
+
+#[derive(Accounts)]
+pub struct CancelAction<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        close = employer,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+}
+
+impl<'info> CancelAction<'info> {
+    pub fn cancel_action(&mut self) -> Result<()> {
+        emit!(ActionCancelled {
+            vault: self.vault.key(),
+            pending_action: self.pending_action.key(),
+            nonce: self.pending_action.nonce,
+        });
+
+        Ok(())
+    }
+}
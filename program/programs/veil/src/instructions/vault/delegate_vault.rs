@@ -20,7 +20,7 @@ pub struct DelegateVault<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", vault.employer.as_ref(), vault.token_mint.as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, VaultAccount>,
@@ -40,9 +40,9 @@ impl<'info> DelegateVault<'info> {
             VeilProgramError::VaultMismatch
         );
         
-        let employer_key = self.vault.employer;
+        let seed_owner = self.vault.seed_owner;
         let token_mint = self.vault.token_mint;
-        let seeds = &[b"vault", employer_key.as_ref(), token_mint.as_ref()];
+        let seeds = &[b"vault", seed_owner.as_ref(), token_mint.as_ref()];
 
         // Delegate the vault PDA to ER using SDK's delegate_pda method
         // delegate_pda(payer, seeds, config)
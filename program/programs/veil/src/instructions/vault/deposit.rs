@@ -18,7 +18,7 @@ pub struct Deposit<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", employer.key().as_ref(), token_mint.key().as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), token_mint.key().as_ref()],
         bump = vault.bump,
         has_one = employer @ VeilProgramError::Unauthorized,
         has_one = token_mint @ VeilProgramError::InvalidMint,
@@ -78,6 +78,7 @@ impl<'info> Deposit<'info> {
         emit!(VaultDeposited {
             vault: self.vault.key(),
             employer: self.employer.key(),
+            funder: self.employer.key(),
             amount,
             available: self.vault.available,
         });
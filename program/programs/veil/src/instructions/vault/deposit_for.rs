@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::error::VeilProgramError;
+use crate::events::VaultDeposited;
+use crate::{Operator, Organization, VaultAccount, VeilConfig, OPERATOR_DEPOSIT};
+
+#[derive(Accounts)]
+pub struct DepositFor<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), token_mint.key().as_ref()],
+        bump = vault.bump,
+        has_one = token_mint @ VeilProgramError::InvalidMint,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        address = vault.vault_ata @ VeilProgramError::InvalidTokenAccount
+    )]
+    pub vault_ata: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub funder_ata: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Lets an operator with the deposit permission fund past the funder allowlist
+    pub operator: Option<Account<'info, Operator>>,
+
+    pub organization: Option<Account<'info, Organization>>,
+}
+
+impl<'info> DepositFor<'info> {
+    pub fn deposit_for(&mut self, amount: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.config.is_mint_allowed(&self.token_mint.key()),
+            VeilProgramError::InvalidMint
+        );
+        let funder = self.funder.key();
+        let vault_key = self.vault.key();
+        let is_operator =
+            self.operator.as_ref().is_some_and(|operator| {
+                operator.has_permission(&vault_key, &funder, OPERATOR_DEPOSIT)
+            }) || self.organization.as_ref().is_some_and(|organization| {
+                organization.has_operator_permission(&vault_key, &funder, OPERATOR_DEPOSIT)
+            });
+        require!(
+            is_operator || self.vault.is_funder_allowed(&funder),
+            VeilProgramError::FunderNotAllowed
+        );
+        require!(
+            self.funder_ata.owner == self.funder.key(),
+            VeilProgramError::Unauthorized
+        );
+        require!(
+            self.funder_ata.mint == self.token_mint.key(),
+            VeilProgramError::InvalidMint
+        );
+        require!(amount > 0, VeilProgramError::InsufficientFunds);
+
+        let cpi_accounts = Transfer {
+            from: self.funder_ata.to_account_info(),
+            to: self.vault_ata.to_account_info(),
+            authority: self.funder.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, amount)?;
+
+        self.vault.available = self
+            .vault
+            .available
+            .checked_add(amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(VaultDeposited {
+            vault: self.vault.key(),
+            employer: self.vault.employer,
+            funder: self.funder.key(),
+            amount,
+            available: self.vault.available,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+use crate::error::VeilProgramError;
+use crate::events::{TipBudgetFunded, TipBudgetWithdrawn};
+use crate::{VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct FundTipBudget<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundTipBudget<'info> {
+    pub fn fund_tip_budget(&mut self, lamports: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(lamports > 0, VeilProgramError::InsufficientFunds);
+
+        let cpi_accounts = Transfer {
+            from: self.employer.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, lamports)?;
+
+        self.vault.tip_budget = self
+            .vault
+            .tip_budget
+            .checked_add(lamports)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(TipBudgetFunded {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            lamports,
+            tip_budget: self.vault.tip_budget,
+        });
+
+        Ok(())
+    }
+
+    pub fn withdraw_tip_budget(&mut self, lamports: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(lamports > 0, VeilProgramError::InsufficientFunds);
+
+        self.vault.tip_budget = self
+            .vault
+            .tip_budget
+            .checked_sub(lamports)
+            .ok_or(VeilProgramError::InsufficientTipBudget)?;
+
+        // The vault is program-owned, so its lamports can be debited directly
+        let vault_info = self.vault.to_account_info();
+        **vault_info.try_borrow_mut_lamports()? -= lamports;
+        **self.employer.to_account_info().try_borrow_mut_lamports()? += lamports;
+
+        emit!(TipBudgetWithdrawn {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            lamports,
+            tip_budget: self.vault.tip_budget,
+        });
+
+        Ok(())
+    }
+}
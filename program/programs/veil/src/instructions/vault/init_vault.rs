@@ -3,7 +3,7 @@ use anchor_spl::token::{Mint, Token, TokenAccount};
 
 use crate::error::VeilProgramError;
 use crate::events::VaultInitialized;
-use crate::{VeilConfig, VaultAccount, ANCHOR_DISCRIMINATOR};
+use crate::{VeilConfig, VaultAccount, VaultScheduleIndex, ANCHOR_DISCRIMINATOR};
 
 #[derive(Accounts)]
 pub struct InitVault<'info> {
@@ -37,6 +37,15 @@ pub struct InitVault<'info> {
     )]
     pub vault_ata: Account<'info, TokenAccount>,
 
+    #[account(
+        init,
+        payer = employer,
+        space = VaultScheduleIndex::space(0),
+        seeds = [b"schedule_index", vault.key().as_ref()],
+        bump
+    )]
+    pub schedule_index: Account<'info, VaultScheduleIndex>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
@@ -62,13 +71,41 @@ impl<'info> InitVault<'info> {
 
         self.vault.set_inner(VaultAccount {
             employer: self.employer.key(),
+            seed_owner: self.employer.key(),
+            pending_employer: None,
             vault_ata: self.vault_ata.key(),
             token_mint: self.token_mint.key(),
             available: 0,
             reserved: 0,
+            fees_owed: 0,
+            tip_budget: 0,
+            funder_allowlist_enabled: false,
+            allowed_funders: Vec::new(),
+            organization: None,
+            destination_allowlist_enabled: false,
+            allowed_destinations: Vec::new(),
+            withdraw_limit: 0,
+            withdraw_window_secs: 0,
+            window_start: 0,
+            window_spent: 0,
+            pending_withdraw_limit: 0,
+            pending_withdraw_window_secs: 0,
+            pending_limit_effective_at: 0,
+            approvers: Vec::new(),
+            approval_threshold: 0,
+            action_nonce: 0,
             bump,
         });
 
+        let vault_key = self.vault.key();
+        let (_, index_bump) =
+            Pubkey::find_program_address(&[b"schedule_index", vault_key.as_ref()], program_id);
+        self.schedule_index.set_inner(VaultScheduleIndex {
+            vault: vault_key,
+            entries: Vec::new(),
+            bump: index_bump,
+        });
+
         emit!(VaultInitialized {
             employer: self.employer.key(),
             vault: self.vault.key(),
@@ -1,11 +1,39 @@
+pub mod accept_vault_ownership;
+pub mod add_operator;
+pub mod approve_action;
+pub mod cancel_action;
 pub mod delegate_vault;
 pub mod deposit;
+pub mod deposit_for;
+pub mod fund_tip_budget;
 pub mod init_vault;
+pub mod propose_action;
+pub mod reconcile_vault;
+pub mod revoke_operator;
+pub mod set_approvers;
+pub mod set_withdraw_limit;
+pub mod transfer_vault_ownership;
 pub mod undelegate_vault;
+pub mod update_destination_allowlist;
+pub mod update_funder_allowlist;
 pub mod withdraw;
 
+pub use accept_vault_ownership::*;
+pub use add_operator::*;
+pub use approve_action::*;
+pub use cancel_action::*;
 pub use delegate_vault::*;
 pub use deposit::*;
+pub use deposit_for::*;
+pub use fund_tip_budget::*;
 pub use init_vault::*;
+pub use propose_action::*;
+pub use reconcile_vault::*;
+pub use revoke_operator::*;
+pub use set_approvers::*;
+pub use set_withdraw_limit::*;
+pub use transfer_vault_ownership::*;
 pub use undelegate_vault::*;
+pub use update_destination_allowlist::*;
+pub use update_funder_allowlist::*;
 pub use withdraw::*;
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ActionProposed;
+use crate::{PendingAction, VaultAccount, VaultAction, VeilConfig, ANCHOR_DISCRIMINATOR};
+
+#[derive(Accounts)]
+pub struct ProposeAction<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        init,
+        payer = employer,
+        space = ANCHOR_DISCRIMINATOR + PendingAction::INIT_SPACE,
+        seeds = [b"pending_action", vault.key().as_ref(), vault.action_nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_action: Account<'info, PendingAction>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeAction<'info> {
+    pub fn propose_action(&mut self, action: VaultAction) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        require!(
+            self.vault.approval_threshold > 0,
+            VeilProgramError::ApprovalsNotEnabled
+        );
+
+        let nonce = self.vault.action_nonce;
+        let vault_key = self.vault.key();
+        let nonce_bytes = nonce.to_le_bytes();
+        let seeds = &[b"pending_action", vault_key.as_ref(), nonce_bytes.as_ref()];
+        let (_, bump) =
+            Pubkey::find_program_address(seeds, self.pending_action.to_account_info().owner);
+
+        self.vault.action_nonce = nonce
+            .checked_add(1)
+            .ok_or(VeilProgramError::InvalidApprovers)?;
+
+        self.pending_action.set_inner(PendingAction {
+            vault: self.vault.key(),
+            nonce,
+            action: action.clone(),
+            approvals: Vec::new(),
+            proposed_at: Clock::get()?.unix_timestamp,
+            bump,
+        });
+
+        emit!(ActionProposed {
+            vault: self.vault.key(),
+            pending_action: self.pending_action.key(),
+            nonce,
+            action,
+        });
+
+        Ok(())
+    }
+}
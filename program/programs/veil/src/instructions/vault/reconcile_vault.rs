@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::error::VeilProgramError;
+use crate::events::VaultReconciled;
+use crate::VaultAccount;
+
+#[derive(Accounts)]
+pub struct ReconcileVault<'info> {
+    /// Anyone may reconcile; the surplus is only ever credited to the employer's vault
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(address = vault.vault_ata @ VeilProgramError::InvalidTokenAccount)]
+    pub vault_ata: Account<'info, TokenAccount>,
+}
+
+impl<'info> ReconcileVault<'info> {
+    pub fn reconcile_vault(&mut self) -> Result<()> {
+        let tracked = self
+            .vault
+            .available
+            .checked_add(self.vault.reserved)
+            .ok_or(VeilProgramError::VaultInvariantViolation)?;
+        require!(
+            self.vault_ata.amount >= tracked,
+            VeilProgramError::VaultInvariantViolation
+        );
+
+        let surplus = self.vault_ata.amount - tracked;
+        self.vault.available = self
+            .vault
+            .available
+            .checked_add(surplus)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        emit!(VaultReconciled {
+            vault: self.vault.key(),
+            employer: self.vault.employer,
+            surplus,
+            available: self.vault.available,
+            reserved: self.vault.reserved,
+        });
+
+        Ok(())
+    }
+}
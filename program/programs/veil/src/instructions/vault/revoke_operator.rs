@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::OperatorRevoked;
+use crate::{Operator, VaultAccount};
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    #[account(
+        mut,
+        close = employer,
+        seeds = [b"operator", vault.key().as_ref(), operator_account.operator.as_ref()],
+        bump = operator_account.bump,
+        has_one = vault @ VeilProgramError::VaultMismatch,
+    )]
+    pub operator_account: Account<'info, Operator>,
+}
+
+impl<'info> RevokeOperator<'info> {
+    pub fn revoke_operator(&mut self) -> Result<()> {
+        emit!(OperatorRevoked {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            operator: self.operator_account.operator,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
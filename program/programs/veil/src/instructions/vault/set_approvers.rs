@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ApproversUpdated;
+use crate::utils::consume_approved_action;
+use crate::{validate_approvers, PendingAction, VaultAccount, VaultAction, VeilConfig};
+
+#[derive(Accounts)]
+pub struct SetApprovers<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> SetApprovers<'info> {
+    /// Replace the approver set. A threshold of 0 lets the employer act alone again.
+    pub fn set_approvers(&mut self, approvers: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        validate_approvers(&approvers, threshold)?;
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::SetApprovers {
+                approvers: approvers.clone(),
+                threshold,
+            },
+        )?;
+
+        self.vault.approvers = approvers.clone();
+        self.vault.approval_threshold = threshold;
+
+        emit!(ApproversUpdated {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            approvers,
+            approval_threshold: threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::WithdrawLimitUpdated;
+use crate::{VaultAccount, VeilConfig, MAX_WITHDRAW_WINDOW_SECS};
+
+#[derive(Accounts)]
+pub struct SetWithdrawLimit<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+}
+
+impl<'info> SetWithdrawLimit<'info> {
+    /// Set `withdraw_limit` per `window_secs` (0 removes the limit). Tightening applies now;
+    /// loosening is staged for WITHDRAW_LIMIT_LOOSEN_DELAY_SECS.
+    pub fn set_withdraw_limit(&mut self, withdraw_limit: u64, window_secs: u64) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        if withdraw_limit > 0 {
+            require!(
+                window_secs > 0 && window_secs <= MAX_WITHDRAW_WINDOW_SECS,
+                VeilProgramError::InvalidWithdrawLimit
+            );
+        } else {
+            require!(window_secs == 0, VeilProgramError::InvalidWithdrawLimit);
+        }
+
+        let clock = Clock::get()?;
+        let now = clock.unix_timestamp as u64;
+        self.vault.apply_pending_withdraw_limit(now);
+        let effective_at = self
+            .vault
+            .set_withdraw_limit(withdraw_limit, window_secs, now)?;
+
+        emit!(WithdrawLimitUpdated {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            withdraw_limit,
+            withdraw_window_secs: window_secs,
+            effective_at,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::VaultOwnershipTransferStarted;
+use crate::utils::consume_approved_action;
+use crate::{PendingAction, VaultAccount, VaultAction, VeilConfig};
+
+#[derive(Accounts)]
+pub struct TransferVaultOwnership<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
+}
+
+impl<'info> TransferVaultOwnership<'info> {
+    /// Nominate `new_employer`, who must accept before anything changes. `None` cancels.
+    pub fn transfer_vault_ownership(&mut self, new_employer: Option<Pubkey>) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        if let Some(new_employer) = new_employer {
+            require!(
+                new_employer != Pubkey::default() && new_employer != self.employer.key(),
+                VeilProgramError::Unauthorized
+            );
+        }
+
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::TransferOwnership { new_employer },
+        )?;
+
+        self.vault.pending_employer = new_employer;
+
+        emit!(VaultOwnershipTransferStarted {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            pending_employer: new_employer,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
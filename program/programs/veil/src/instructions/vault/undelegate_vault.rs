@@ -18,7 +18,7 @@ pub struct UndelegateVault<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", vault.employer.as_ref(), vault.token_mint.as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
         bump = vault.bump,
     )]
     pub vault: Account<'info, VaultAccount>,
@@ -43,11 +43,11 @@ impl<'info> UndelegateVault<'info> {
         );
 
         // Prepare seeds for the vault PDA
-        let employer_key = self.vault.employer;
+        let seed_owner = self.vault.seed_owner;
         let token_mint = self.vault.token_mint;
         let seeds = vec![
             b"vault".to_vec(),
-            employer_key.as_ref().to_vec(),
+            seed_owner.as_ref().to_vec(),
             token_mint.as_ref().to_vec(),
         ];
 
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::DestinationAllowlistUpdated;
+use crate::{validate_destination_allowlist, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct UpdateDestinationAllowlist<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+}
+
+impl<'info> UpdateDestinationAllowlist<'info> {
+    pub fn update_destination_allowlist(
+        &mut self,
+        allowlist_enabled: bool,
+        allowed_destinations: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        validate_destination_allowlist(&allowed_destinations, allowlist_enabled)?;
+
+        self.vault.destination_allowlist_enabled = allowlist_enabled;
+        self.vault.allowed_destinations = allowed_destinations.clone();
+
+        emit!(DestinationAllowlistUpdated {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            allowlist_enabled,
+            allowed_destinations,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::FunderAllowlistUpdated;
+use crate::{validate_funder_allowlist, VaultAccount, VeilConfig};
+
+#[derive(Accounts)]
+pub struct UpdateFunderAllowlist<'info> {
+    #[account(mut)]
+    pub employer: Signer<'info>,
+
+    #[account(
+        seeds = [b"veil_config"],
+        bump
+    )]
+    pub config: Account<'info, VeilConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", vault.seed_owner.as_ref(), vault.token_mint.as_ref()],
+        bump = vault.bump,
+        has_one = employer @ VeilProgramError::Unauthorized,
+    )]
+    pub vault: Account<'info, VaultAccount>,
+}
+
+impl<'info> UpdateFunderAllowlist<'info> {
+    pub fn update_funder_allowlist(
+        &mut self,
+        allowlist_enabled: bool,
+        allowed_funders: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(!self.config.paused, VeilProgramError::Paused);
+        validate_funder_allowlist(&allowed_funders, allowlist_enabled)?;
+
+        self.vault.funder_allowlist_enabled = allowlist_enabled;
+        self.vault.allowed_funders = allowed_funders.clone();
+
+        emit!(FunderAllowlistUpdated {
+            vault: self.vault.key(),
+            employer: self.employer.key(),
+            allowlist_enabled,
+            allowed_funders,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
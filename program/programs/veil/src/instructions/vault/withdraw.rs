@@ -3,7 +3,10 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
 use crate::error::VeilProgramError;
 use crate::events::VaultWithdrawn;
-use crate::{VaultAccount, VeilConfig};
+use crate::utils::consume_approved_action;
+use crate::{
+    member_organization, Organization, PendingAction, VaultAccount, VaultAction, VeilConfig,
+};
 
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
@@ -18,7 +21,7 @@ pub struct Withdraw<'info> {
 
     #[account(
         mut,
-        seeds = [b"vault", employer.key().as_ref(), token_mint.key().as_ref()],
+        seeds = [b"vault", vault.seed_owner.as_ref(), token_mint.key().as_ref()],
         bump = vault.bump,
         has_one = employer @ VeilProgramError::Unauthorized,
         has_one = token_mint @ VeilProgramError::InvalidMint,
@@ -31,12 +34,20 @@ pub struct Withdraw<'info> {
     )]
     pub vault_ata: Account<'info, TokenAccount>,
 
+    /// Any token account of the vault mint, subject to the vault's destination allowlist
     #[account(mut)]
-    pub employer_ata: Account<'info, TokenAccount>,
+    pub destination_ata: Account<'info, TokenAccount>,
 
     pub token_mint: Account<'info, Mint>,
 
     pub token_program: Program<'info, Token>,
+
+    /// Required for organization members so the org's default limit applies
+    pub organization: Option<Account<'info, Organization>>,
+
+    /// Required once the vault has an approval threshold
+    #[account(mut)]
+    pub pending_action: Option<Account<'info, PendingAction>>,
 }
 
 impl<'info> Withdraw<'info> {
@@ -47,29 +58,51 @@ impl<'info> Withdraw<'info> {
             VeilProgramError::InvalidMint
         );
         require!(
-            self.employer_ata.owner == self.employer.key(),
-            VeilProgramError::Unauthorized
+            self.destination_ata.mint == self.token_mint.key(),
+            VeilProgramError::InvalidMint
         );
         require!(
-            self.employer_ata.mint == self.token_mint.key(),
-            VeilProgramError::InvalidMint
+            self.vault.is_destination_allowed(&self.destination_ata.key()),
+            VeilProgramError::DestinationNotAllowed
         );
         require!(amount > 0, VeilProgramError::InsufficientFunds);
         require!(
-            amount <= self.vault.available,
+            amount <= self.vault.available.saturating_sub(self.vault.fees_owed),
             VeilProgramError::InsufficientFunds
         );
 
-        // Transfer tokens from vault to employer
-        let employer_key = self.employer.key();
+        consume_approved_action(
+            &self.vault,
+            self.pending_action.as_ref(),
+            &self.employer.to_account_info(),
+            VaultAction::Withdraw {
+                amount,
+                destination: self.destination_ata.key(),
+            },
+        )?;
+        let default_limit = member_organization(&self.vault, self.organization.as_ref())?
+            .map_or((0, 0), |organization| {
+                (
+                    organization.default_withdraw_limit,
+                    organization.default_withdraw_window_secs,
+                )
+            });
+        self.vault.record_withdrawal(
+            amount,
+            Clock::get()?.unix_timestamp as u64,
+            default_limit,
+        )?;
+
+        // Transfer tokens from vault to the destination
+        let seed_owner = self.vault.seed_owner;
         let token_mint_key = self.token_mint.key();
         let bump = self.vault.bump;
-        let seeds = &[b"vault", employer_key.as_ref(), token_mint_key.as_ref(), &[bump]];
+        let seeds = &[b"vault", seed_owner.as_ref(), token_mint_key.as_ref(), &[bump]];
         let signer = &[&seeds[..]];
 
         let cpi_accounts = Transfer {
             from: self.vault_ata.to_account_info(),
-            to: self.employer_ata.to_account_info(),
+            to: self.destination_ata.to_account_info(),
             authority: self.vault.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
@@ -88,6 +121,7 @@ impl<'info> Withdraw<'info> {
         emit!(VaultWithdrawn {
             vault: self.vault.key(),
             employer: self.employer.key(),
+            destination: self.destination_ata.key(),
             amount,
             available: self.vault.available,
         });
@@ -14,6 +14,7 @@ pub use error::*;
 pub use events::*;
 pub use instructions::*;
 pub use state::*;
+pub use utils::PaymentSplit;
 
 declare_id!("6cibjMX1UwnqSxRkiSBp89NV5Z8Ws3M9i5kizxm8ZnTS");
 
@@ -54,6 +55,13 @@ pub mod veil {
     pub fn set_er_authority(ctx: Context<SetErAuthority>, new_er_authority: Pubkey) -> Result<()> {
         ctx.accounts.set_er_auth(new_er_authority)
     }
+    pub fn set_protocol_fee(
+        ctx: Context<SetProtocolFee>,
+        fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.set_protocol_fee(fee_bps, treasury)
+    }
     pub fn pause(ctx: Context<Pause>) -> Result<()> {
         ctx.accounts.pause()
     }
@@ -69,18 +77,132 @@ pub mod veil {
     pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         ctx.accounts.deposit_to_vault(amount)
     }
+    pub fn deposit_for(ctx: Context<DepositFor>, amount: u64) -> Result<()> {
+        ctx.accounts.deposit_for(amount)
+    }
     pub fn withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
         ctx.accounts.withdraw_from_vault(amount)
     }
+    pub fn update_funder_allowlist(
+        ctx: Context<UpdateFunderAllowlist>,
+        allowlist_enabled: bool,
+        allowed_funders: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.update_funder_allowlist(allowlist_enabled, allowed_funders)
+    }
+    pub fn update_destination_allowlist(
+        ctx: Context<UpdateDestinationAllowlist>,
+        allowlist_enabled: bool,
+        allowed_destinations: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.update_destination_allowlist(allowlist_enabled, allowed_destinations)
+    }
+    pub fn transfer_vault_ownership(
+        ctx: Context<TransferVaultOwnership>,
+        new_employer: Option<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.transfer_vault_ownership(new_employer)
+    }
+    pub fn accept_vault_ownership<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AcceptVaultOwnership<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.accept_vault_ownership(ctx.remaining_accounts)
+    }
+    pub fn add_operator(ctx: Context<AddOperator>, operator: Pubkey, permissions: u8) -> Result<()> {
+        ctx.accounts.add_operator(operator, permissions)
+    }
+    pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+        ctx.accounts.revoke_operator()
+    }
+    pub fn set_approvers(
+        ctx: Context<SetApprovers>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        ctx.accounts.set_approvers(approvers, threshold)
+    }
+    pub fn propose_action(ctx: Context<ProposeAction>, action: VaultAction) -> Result<()> {
+        ctx.accounts.propose_action(action)
+    }
+    pub fn approve_action(ctx: Context<ApproveAction>) -> Result<()> {
+        ctx.accounts.approve_action()
+    }
+    pub fn cancel_action(ctx: Context<CancelAction>) -> Result<()> {
+        ctx.accounts.cancel_action()
+    }
+    pub fn set_withdraw_limit(
+        ctx: Context<SetWithdrawLimit>,
+        withdraw_limit: u64,
+        window_secs: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_withdraw_limit(withdraw_limit, window_secs)
+    }
+    pub fn reconcile_vault(ctx: Context<ReconcileVault>) -> Result<()> {
+        ctx.accounts.reconcile_vault()
+    }
     pub fn delegate_vault(ctx: Context<DelegateVault>) -> Result<()> {
         ctx.accounts.delegate_vault()
     }
     pub fn undelegate_vault(ctx: Context<UndelegateVault>) -> Result<()> {
         ctx.accounts.undelegate_vault()
     }
+    pub fn fund_tip_budget(ctx: Context<FundTipBudget>, lamports: u64) -> Result<()> {
+        ctx.accounts.fund_tip_budget(lamports)
+    }
+    pub fn withdraw_tip_budget(ctx: Context<FundTipBudget>, lamports: u64) -> Result<()> {
+        ctx.accounts.withdraw_tip_budget(lamports)
+    }
+
+    // organization ixs
+
+    pub fn create_organization(
+        ctx: Context<CreateOrganization>,
+        name_hash: [u8; 32],
+        admins: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.create_organization(name_hash, admins)
+    }
+
+    pub fn update_organization_admins(
+        ctx: Context<ManageOrganization>,
+        admins: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts.update_organization_admins(admins)
+    }
+
+    pub fn set_organization_policy(
+        ctx: Context<ManageOrganization>,
+        default_withdraw_limit: u64,
+        default_withdraw_window_secs: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_organization_policy(default_withdraw_limit, default_withdraw_window_secs)
+    }
+
+    pub fn set_organization_operator(
+        ctx: Context<ManageOrganization>,
+        operator: Pubkey,
+        permissions: u8,
+    ) -> Result<()> {
+        ctx.accounts.set_organization_operator(operator, permissions)
+    }
+
+    pub fn join_organization(ctx: Context<OrganizationMembership>) -> Result<()> {
+        ctx.accounts.join_organization()
+    }
+
+    pub fn leave_organization(ctx: Context<OrganizationMembership>) -> Result<()> {
+        ctx.accounts.leave_organization()
+    }
+
+    pub fn refresh_organization_totals<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefreshOrganizationTotals<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.refresh_organization_totals(ctx.remaining_accounts)
+    }
 
     // scheduling ixs
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_schedule(
         ctx: Context<CreateSchedule>,
         schedule_id: [u8; 32],
@@ -90,6 +212,10 @@ pub mod veil {
         merkle_root: [u8; 32],
         total_recipients: u16,
         er_job_id: [u8; 32],
+        missed_period_policy: MissedPeriodPolicy,
+        start_at: Option<u64>,
+        end_at: Option<u64>,
+        max_executions: Option<u64>,
     ) -> Result<()> {
         ctx.accounts.create_schedule(
             schedule_id,
@@ -99,6 +225,29 @@ pub mod veil {
             merkle_root,
             total_recipients,
             er_job_id,
+            missed_period_policy,
+            start_at,
+            end_at,
+            max_executions,
+        )
+    }
+
+    pub fn create_one_shot_schedule(
+        ctx: Context<CreateOneShotSchedule>,
+        schedule_id: [u8; 32],
+        execute_at: u64,
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.create_one_shot_schedule(
+            schedule_id,
+            execute_at,
+            reserved_amount,
+            merkle_root,
+            total_recipients,
+            er_job_id,
         )
     }
 
@@ -110,6 +259,7 @@ pub mod veil {
         ctx.accounts.pause_schedule(pause)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_schedule(
         ctx: Context<UpdateSchedule>,
         interval_secs: u64,
@@ -117,6 +267,10 @@ pub mod veil {
         per_execution_amount: u64,
         merkle_root: [u8; 32],
         total_recipients: u16,
+        missed_period_policy: MissedPeriodPolicy,
+        start_at: Option<u64>,
+        end_at: Option<u64>,
+        max_executions: Option<u64>,
     ) -> Result<()> {
         ctx.accounts.update_schedule(
             interval_secs,
@@ -124,9 +278,130 @@ pub mod veil {
             per_execution_amount,
             merkle_root,
             total_recipients,
+            missed_period_policy,
+            start_at,
+            end_at,
+            max_executions,
+        )
+    }
+
+    pub fn create_stream_schedule(
+        ctx: Context<CreateLeafSchedule>,
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.create_stream_schedule(
+            schedule_id,
+            reserved_amount,
+            merkle_root,
+            total_recipients,
+            er_job_id,
+        )
+    }
+
+    pub fn create_vesting_schedule(
+        ctx: Context<CreateLeafSchedule>,
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.create_vesting_schedule(
+            schedule_id,
+            reserved_amount,
+            merkle_root,
+            total_recipients,
+            er_job_id,
         )
     }
 
+    pub fn revoke_vesting(
+        ctx: Context<RevokeVesting>,
+        recipient: Pubkey,
+        grant: VestingGrant,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts
+            .revoke_vesting(recipient, grant, leaf_index, proof)
+    }
+
+    pub fn fund_schedule(ctx: Context<FundSchedule>, amount: u64) -> Result<()> {
+        ctx.accounts.fund_schedule(amount)
+    }
+
+    pub fn defund_schedule(ctx: Context<FundSchedule>, amount: u64) -> Result<()> {
+        ctx.accounts.defund_schedule(amount)
+    }
+
+    pub fn set_auto_refill(ctx: Context<SetAutoRefill>, target_cycles: u16) -> Result<()> {
+        ctx.accounts.set_auto_refill(target_cycles)
+    }
+
+    pub fn stage_schedule_update(
+        ctx: Context<StageScheduleUpdate>,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        per_execution_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .stage_schedule_update(merkle_root, total_recipients, per_execution_amount)
+    }
+
+    pub fn withhold_recipient(
+        ctx: Context<WithholdRecipient>,
+        leaf_index: u16,
+        withhold: bool,
+    ) -> Result<()> {
+        ctx.accounts.withhold_recipient(leaf_index, withhold)
+    }
+
+    pub fn set_claim_window(ctx: Context<SetClaimWindow>, claim_window_secs: u64) -> Result<()> {
+        ctx.accounts.set_claim_window(claim_window_secs)
+    }
+
+    pub fn fund_rent_budget(ctx: Context<FundRentBudget>, lamports: u64) -> Result<()> {
+        ctx.accounts.fund_rent_budget(lamports)
+    }
+
+    pub fn withdraw_rent_budget(ctx: Context<FundRentBudget>, lamports: u64) -> Result<()> {
+        ctx.accounts.withdraw_rent_budget(lamports)
+    }
+
+    pub fn set_payout_mode(ctx: Context<SetPayoutMode>, payout_mode: PayoutMode) -> Result<()> {
+        ctx.accounts.set_payout_mode(payout_mode)
+    }
+
+    pub fn set_receipt_mode(ctx: Context<SetReceiptMode>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_receipt_mode(enabled)
+    }
+
+    pub fn set_keeper_tip(ctx: Context<SetKeeperTip>, keeper_tip: u64) -> Result<()> {
+        ctx.accounts.set_keeper_tip(keeper_tip)
+    }
+
+    pub fn close_receipts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseReceipts<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.close_receipts(ctx.remaining_accounts)
+    }
+
+    pub fn sync_schedule_employer(ctx: Context<SyncScheduleEmployer>) -> Result<()> {
+        ctx.accounts.sync_schedule_employer()
+    }
+
+    pub fn sync_schedule_index(ctx: Context<SyncScheduleIndex>) -> Result<()> {
+        ctx.accounts.sync_schedule_index()
+    }
+
+    pub fn close_schedule(ctx: Context<CloseSchedule>, schedule_id: [u8; 32]) -> Result<()> {
+        ctx.accounts.close_schedule(schedule_id)
+    }
+
     pub fn delegate_schedule(ctx: Context<DelegateSchedule>, schedule_id: [u8; 32]) -> Result<()> {
         ctx.accounts.delegate_schedule(schedule_id)
     }
@@ -138,16 +413,89 @@ pub mod veil {
         ctx.accounts.undelegate_schedule(schedule_id)
     }
 
-    pub fn claim_payment(
-        ctx: Context<ClaimPayment>,
+    pub fn claim_payment<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimPayment<'info>>,
+        schedule_id: [u8; 32],
+        recipient: Pubkey,
+        amount: u64,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
+        splits: Vec<PaymentSplit>,
+    ) -> Result<()> {
+        ctx.accounts.claim_payment(
+            schedule_id,
+            recipient,
+            amount,
+            leaf_index,
+            proof,
+            splits,
+            ctx.remaining_accounts,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_past_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimPastBatch<'info>>,
         schedule_id: [u8; 32],
+        batch: u64,
         recipient: Pubkey,
         amount: u64,
         leaf_index: u16,
         proof: Vec<[u8; 32]>,
+        splits: Vec<PaymentSplit>,
+    ) -> Result<()> {
+        ctx.accounts.claim_past_batch(
+            schedule_id,
+            batch,
+            recipient,
+            amount,
+            leaf_index,
+            proof,
+            splits,
+            ctx.remaining_accounts,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_stream(
+        ctx: Context<ClaimStream>,
+        schedule_id: [u8; 32],
+        recipient: Pubkey,
+        rate_per_sec: u64,
+        start_time: u64,
+        end_time: u64,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.claim_stream(
+            schedule_id,
+            recipient,
+            rate_per_sec,
+            start_time,
+            end_time,
+            leaf_index,
+            proof,
+        )
+    }
+
+    pub fn claim_vested(
+        ctx: Context<ClaimVested>,
+        schedule_id: [u8; 32],
+        recipient: Pubkey,
+        grant: VestingGrant,
+        leaf_index: u16,
+        proof: Vec<[u8; 32]>,
     ) -> Result<()> {
         ctx.accounts
-            .claim_payment(schedule_id, recipient, amount, leaf_index, proof)
+            .claim_vested(schedule_id, recipient, grant, leaf_index, proof)
+    }
+
+    pub fn close_batch_record(ctx: Context<CloseBatchRecord>) -> Result<()> {
+        ctx.accounts.close_batch_record()
+    }
+
+    pub fn settle_batch(ctx: Context<SettleBatch>, schedule_id: [u8; 32]) -> Result<()> {
+        ctx.accounts.settle_batch(schedule_id)
     }
 
     // er ixs
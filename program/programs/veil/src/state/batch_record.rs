@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PayoutMode;
+
+#[account]
+#[derive(InitSpace)]
+pub struct BatchRecord {
+    pub schedule: Pubkey,
+    pub batch: u64, // Batch number as counted by last_executed_batch
+    pub merkle_root: [u8; 32],
+    pub total_recipients: u16,
+    pub payout_mode: PayoutMode,
+    pub per_execution_amount: u64, // Pool that Bps leaves are a share of
+    pub paid_bitmap: [u8; 128],
+    pub withheld_bitmap: [u8; 128],
+    pub held_amount: u64, // Reserve kept back for late claims
+    pub expires_at: u64,
+    pub bump: u8,
+}
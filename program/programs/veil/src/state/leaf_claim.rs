@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::utils::create_pda_from_budget;
+use crate::{ScheduleAccount, ANCHOR_DISCRIMINATOR};
+
+#[account]
+#[derive(InitSpace)]
+pub struct LeafClaim {
+    pub schedule: Pubkey,
+    pub leaf_index: u16,
+    pub claimed: u64,            // Streamed or vested amount paid out so far
+    pub vested_cap: Option<u64>, // Vested amount frozen by revoke_vesting
+    pub bump: u8,
+}
+
+impl LeafClaim {
+    /// Reads a leaf's claim counter, creating its PDA from the schedule's rent budget
+    /// the first time the leaf is touched.
+    pub fn load_or_create<'info>(
+        schedule: &mut Account<'info, ScheduleAccount>,
+        leaf_claim: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        leaf_index: u16,
+    ) -> Result<LeafClaim> {
+        let schedule_key = schedule.key();
+        let leaf_bytes = leaf_index.to_le_bytes();
+        let (leaf_claim_pda, bump) = Pubkey::find_program_address(
+            &[b"leaf_claim", schedule_key.as_ref(), leaf_bytes.as_ref()],
+            &crate::ID,
+        );
+        require!(
+            leaf_claim.key() == leaf_claim_pda,
+            VeilProgramError::InvalidLeafClaim
+        );
+
+        if !leaf_claim.data_is_empty() {
+            return LeafClaim::try_deserialize(&mut &leaf_claim.try_borrow_data()?[..]);
+        }
+
+        let lamports = create_pda_from_budget(
+            &schedule.to_account_info(),
+            leaf_claim,
+            system_program,
+            ANCHOR_DISCRIMINATOR + LeafClaim::INIT_SPACE,
+            &[
+                b"leaf_claim",
+                schedule_key.as_ref(),
+                leaf_bytes.as_ref(),
+                &[bump],
+            ],
+        )?;
+        schedule.rent_budget = schedule
+            .rent_budget
+            .checked_sub(lamports)
+            .ok_or(VeilProgramError::InsufficientRentBudget)?;
+
+        Ok(LeafClaim {
+            schedule: schedule_key,
+            leaf_index,
+            claimed: 0,
+            vested_cap: None,
+            bump,
+        })
+    }
+}
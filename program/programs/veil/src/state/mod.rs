@@ -1,7 +1,23 @@
+pub mod batch_record;
+pub mod leaf_claim;
+pub mod operator;
+pub mod organization_account;
+pub mod payment_receipt;
+pub mod pending_action;
 pub mod schedule_account;
 pub mod vault_account;
+pub mod vault_schedule_index;
 pub mod veil_config;
+pub mod vesting_grant;
 
+pub use batch_record::*;
+pub use leaf_claim::*;
+pub use operator::*;
+pub use organization_account::*;
+pub use payment_receipt::*;
+pub use pending_action::*;
 pub use schedule_account::*;
 pub use vault_account::*;
+pub use vault_schedule_index::*;
 pub use veil_config::*;
+pub use vesting_grant::*;
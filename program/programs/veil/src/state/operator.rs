@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::Organization;
+
+/// A key the employer lets manage part of a vault, seeded by (vault, operator).
+#[account]
+#[derive(InitSpace)]
+pub struct Operator {
+    pub vault: Pubkey,
+    pub operator: Pubkey,
+    pub permissions: u8, // OPERATOR_* bits
+    pub bump: u8,
+}
+
+pub const OPERATOR_PAUSE_SCHEDULE: u8 = 1 << 0;
+pub const OPERATOR_UPDATE_SCHEDULE: u8 = 1 << 1;
+pub const OPERATOR_CREATE_SCHEDULE: u8 = 1 << 2;
+pub const OPERATOR_DEPOSIT: u8 = 1 << 3;
+pub const OPERATOR_CANCEL: u8 = 1 << 4;
+pub const OPERATOR_ALL_PERMISSIONS: u8 = OPERATOR_PAUSE_SCHEDULE
+    | OPERATOR_UPDATE_SCHEDULE
+    | OPERATOR_CREATE_SCHEDULE
+    | OPERATOR_DEPOSIT
+    | OPERATOR_CANCEL;
+
+impl Operator {
+    pub fn has_permission(&self, vault: &Pubkey, authority: &Pubkey, permission: u8) -> bool {
+        self.vault == *vault && self.operator == *authority && self.permissions & permission != 0
+    }
+}
+
+/// Passes if `authority` is the employer, or holds `permission` on `vault` through an
+/// `Operator` PDA or an operator role of the vault's organization.
+pub fn require_employer_or_operator(
+    employer: &Pubkey,
+    vault: &Pubkey,
+    authority: &Pubkey,
+    operator: Option<&Account<Operator>>,
+    organization: Option<&Account<Organization>>,
+    permission: u8,
+) -> Result<()> {
+    if authority == employer {
+        return Ok(());
+    }
+
+    let allowed = operator
+        .is_some_and(|operator| operator.has_permission(vault, authority, permission))
+        || organization.is_some_and(|organization| {
+            organization.has_operator_permission(vault, authority, permission)
+        });
+    require!(allowed, VeilProgramError::Unauthorized);
+
+    Ok(())
+}
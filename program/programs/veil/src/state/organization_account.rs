@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::{validate_allowlist, VaultAccount, MAX_ORG_ADMINS};
+
+/// Groups a company's vaults (one per mint) under shared admins and policies.
+#[account]
+#[derive(InitSpace)]
+pub struct Organization {
+    pub creator: Pubkey,
+    pub name_hash: [u8; 32],
+    #[max_len(8)]
+    pub admins: Vec<Pubkey>,
+    #[max_len(16)]
+    pub vaults: Vec<Pubkey>,
+    pub default_withdraw_limit: u64, // applies to member vaults without their own limit (0 = none)
+    pub default_withdraw_window_secs: u64,
+    #[max_len(8)]
+    pub operators: Vec<OrgOperator>, // operator roles valid on every member vault
+    pub total_available: u64, // aggregates as of totals_updated_at
+    pub total_reserved: u64,
+    pub totals_updated_at: i64,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct OrgOperator {
+    pub operator: Pubkey,
+    pub permissions: u8, // OPERATOR_* bits
+}
+
+impl Organization {
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        self.admins.contains(key)
+    }
+
+    pub fn has_operator_permission(
+        &self,
+        vault: &Pubkey,
+        authority: &Pubkey,
+        permission: u8,
+    ) -> bool {
+        self.vaults.contains(vault)
+            && self
+                .operators
+                .iter()
+                .any(|entry| entry.operator == *authority && entry.permissions & permission != 0)
+    }
+}
+
+pub fn validate_org_admins(admins: &[Pubkey]) -> Result<()> {
+    validate_allowlist(
+        admins,
+        true,
+        MAX_ORG_ADMINS,
+        VeilProgramError::InvalidOrgAdmins,
+        VeilProgramError::InvalidOrgAdmins,
+        VeilProgramError::InvalidOrgAdmins,
+    )
+}
+
+/// Checks `organization` is the one `vault` belongs to. Member vaults must pass it so
+/// org policies can't be skipped; returns it for vaults that have an organization.
+pub fn member_organization<'a, 'info>(
+    vault: &Account<'info, VaultAccount>,
+    organization: Option<&'a Account<'info, Organization>>,
+) -> Result<Option<&'a Account<'info, Organization>>> {
+    let Some(expected) = vault.organization else {
+        return Ok(None);
+    };
+
+    let organization = organization.ok_or(VeilProgramError::OrganizationMismatch)?;
+    require!(
+        organization.key() == expected,
+        VeilProgramError::OrganizationMismatch
+    );
+
+    Ok(Some(organization))
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentReceipt {
+    pub schedule: Pubkey,
+    pub batch: u64,
+    pub leaf_index: u16,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{MissedPeriodPolicy, PayoutMode};
+use crate::VaultAccount;
+
+/// Employer action held until enough of the vault's approvers sign off.
+#[account]
+#[derive(InitSpace)]
+pub struct PendingAction {
+    pub vault: Pubkey,
+    pub nonce: u64, // vault.action_nonce when proposed
+    pub action: VaultAction,
+    #[max_len(8)]
+    pub approvals: Vec<Pubkey>,
+    pub proposed_at: i64,
+    pub bump: u8,
+}
+
+/// A sensitive employer action and its arguments, matched field-for-field on execution.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub enum VaultAction {
+    Withdraw {
+        amount: u64,
+        destination: Pubkey,
+    },
+    CreateSchedule {
+        schedule_id: [u8; 32],
+        interval_secs: u64,
+        reserved_amount: u64,
+        per_execution_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+        missed_period_policy: MissedPeriodPolicy,
+        start_at: Option<u64>,
+        end_at: Option<u64>,
+        max_executions: Option<u64>,
+    },
+    UpdateSchedule {
+        schedule: Pubkey,
+        interval_secs: u64,
+        reserved_amount: u64,
+        per_execution_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        missed_period_policy: MissedPeriodPolicy,
+        start_at: Option<u64>,
+        end_at: Option<u64>,
+        max_executions: Option<u64>,
+    },
+    CancelSchedule {
+        schedule: Pubkey,
+    },
+    TransferOwnership {
+        new_employer: Option<Pubkey>,
+    },
+    SetApprovers {
+        #[max_len(8)]
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+    },
+    RevokeVesting {
+        schedule: Pubkey,
+        leaf_index: u16,
+    },
+    CreateOneShotSchedule {
+        schedule_id: [u8; 32],
+        execute_at: u64,
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    },
+    CreateStreamSchedule {
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    },
+    CreateVestingSchedule {
+        schedule_id: [u8; 32],
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+    },
+    StageScheduleUpdate {
+        schedule: Pubkey,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        per_execution_amount: u64,
+    },
+    FundSchedule {
+        schedule: Pubkey,
+        amount: u64,
+    },
+    DefundSchedule {
+        schedule: Pubkey,
+        amount: u64,
+    },
+    WithholdRecipient {
+        schedule: Pubkey,
+        leaf_index: u16,
+        withhold: bool,
+    },
+    SetPayoutMode {
+        schedule: Pubkey,
+        payout_mode: PayoutMode,
+    },
+}
+
+impl PendingAction {
+    /// Approvals from keys that are still approvers on the vault.
+    pub fn approval_count(&self, vault: &VaultAccount) -> u8 {
+        self.approvals
+            .iter()
+            .filter(|approver| vault.approvers.contains(approver))
+            .count() as u8
+    }
+}
@@ -1,13 +1,20 @@
 use anchor_lang::prelude::*;
 
+use crate::error::VeilProgramError;
+use crate::{VaultAccount, BPS_DENOMINATOR};
+
 #[account]
 #[derive(InitSpace)]
 pub struct ScheduleAccount {
     pub employer: Pubkey,
     pub vault: Pubkey,
     pub status: ScheduleStatus,
+    pub kind: ScheduleKind,
     pub interval_secs: u64,
     pub next_execution: u64,
+    pub anchor_time: u64, // Due times are anchor_time + k * interval_secs
+    pub missed_period_policy: MissedPeriodPolicy,
+    pub end_at: Option<u64>, // No batch is due or paid after this time
     pub reserved_amount: u64,
     pub per_execution_amount: u64, // Total amount paid per execution interval
     pub er_job_id: [u8; 32],
@@ -15,8 +22,23 @@ pub struct ScheduleAccount {
     pub total_recipients: u16,  // Total number of recipients in the tree
     pub paid_count: u16,        // Number of recipients paid in current batch
     pub paid_bitmap: [u8; 128], // Bitmap tracking paid recipients (1024 max, 1 bit per recipient)
-    pub last_executed_batch: u64, // replay protection
-    pub batch_start_time: u64,  // When current batch started (0 if not started)
+    pub withheld_bitmap: [u8; 128], // Leaves on hold; kept across batches until released or the root changes
+    pub last_executed_batch: u64,   // replay protection
+    pub max_executions: Option<u64>, // Batches to run before completing (None = unbounded)
+    pub batch_paid_amount: u64,     // Amount paid out in current batch
+    pub payout_mode: PayoutMode,
+    pub batch_paid_bps: u64,     // Share claimed in current batch (Bps mode)
+    pub batch_dust: u64,         // Rounding remainder of the shares claimed in current batch
+    pub auto_refill_cycles: u16, // Cycles to keep reserved from vault.available (0 = off)
+    pub pending_merkle_root: Option<[u8; 32]>, // Staged root applied at the next batch boundary
+    pub pending_total_recipients: u16,
+    pub pending_per_execution_amount: u64,
+    pub batch_start_time: u64, // When current batch started (0 if not started)
+    pub claim_window_secs: u64, // How long unclaimed pay stays claimable after a batch closes (0 = off)
+    pub rent_budget: u64,       // Lamports held by this account for PDAs the program creates
+    pub receipt_mode: bool,     // Write a PaymentReceipt PDA for every claim
+    pub keeper_tip: u64,        // Lamports from vault.tip_budget paid to the claim signer
+    pub open_batch_records: u16, // BatchRecords not yet closed; the schedule can't close before them
     pub bump: u8,
 }
 
@@ -25,4 +47,317 @@ pub enum ScheduleStatus {
     Active,
     Paused,
     Cancelled,
+    Completed,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum ScheduleKind {
+    Interval,
+    OneShot, // Single batch at next_execution, then completes
+    Stream,  // Leaves accrue per second and are claimed any time via claim_stream
+    Vesting, // Leaves unlock per VestingGrant and are claimed via claim_vested
+}
+
+/// What happens to pay dates that were missed because a batch settled late.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum MissedPeriodPolicy {
+    /// Jump straight to the first due time after the settling claim.
+    Skip,
+    /// Keep every due time; overdue batches become claimable one after another.
+    CatchUp,
+}
+
+/// What the value committed in each leaf means.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
+pub enum PayoutMode {
+    /// Absolute token amount.
+    Amount,
+    /// Share of `per_execution_amount` in basis points.
+    Bps,
+}
+
+/// The fields that differ between schedule kinds, passed to `ScheduleAccount::init`.
+pub struct ScheduleCadence {
+    pub kind: ScheduleKind,
+    pub interval_secs: u64,
+    pub next_execution: u64, // Also the anchor of the due-time grid
+    pub missed_period_policy: MissedPeriodPolicy,
+    pub end_at: Option<u64>,
+    pub per_execution_amount: u64,
+    pub max_executions: Option<u64>,
+}
+
+impl ScheduleAccount {
+    /// A new active schedule with an empty batch and every optional feature switched off.
+    #[allow(clippy::too_many_arguments)]
+    pub fn init(
+        employer: Pubkey,
+        vault: Pubkey,
+        cadence: ScheduleCadence,
+        reserved_amount: u64,
+        merkle_root: [u8; 32],
+        total_recipients: u16,
+        er_job_id: [u8; 32],
+        bump: u8,
+    ) -> Self {
+        Self {
+            employer,
+            vault,
+            status: ScheduleStatus::Active,
+            kind: cadence.kind,
+            interval_secs: cadence.interval_secs,
+            next_execution: cadence.next_execution,
+            anchor_time: cadence.next_execution,
+            missed_period_policy: cadence.missed_period_policy,
+            end_at: cadence.end_at,
+            reserved_amount,
+            per_execution_amount: cadence.per_execution_amount,
+            er_job_id,
+            merkle_root,
+            total_recipients,
+            paid_count: 0,
+            paid_bitmap: [0u8; 128],
+            withheld_bitmap: [0u8; 128],
+            last_executed_batch: 0,
+            max_executions: cadence.max_executions,
+            batch_paid_amount: 0,
+            payout_mode: PayoutMode::Amount,
+            batch_paid_bps: 0,
+            batch_dust: 0,
+            auto_refill_cycles: 0,
+            pending_merkle_root: None,
+            pending_total_recipients: 0,
+            pending_per_execution_amount: 0,
+            batch_start_time: 0, // Will be set on first claim
+            claim_window_secs: 0,
+            rent_budget: 0,
+            receipt_mode: false,
+            keeper_tip: 0,
+            open_batch_records: 0,
+            bump,
+        }
+    }
+
+    /// Next due time on the `anchor_time` grid, strictly after the current `next_execution`.
+    pub fn next_due_time(&self, current_time: u64) -> Result<u64> {
+        let floor = match self.missed_period_policy {
+            MissedPeriodPolicy::CatchUp => self.next_execution,
+            MissedPeriodPolicy::Skip => self.next_execution.max(current_time),
+        };
+        if floor < self.anchor_time {
+            return Ok(self.anchor_time);
+        }
+
+        let periods = (floor - self.anchor_time) / self.interval_secs + 1;
+        periods
+            .checked_mul(self.interval_secs)
+            .and_then(|offset| self.anchor_time.checked_add(offset))
+            .ok_or(VeilProgramError::InvalidScheduleInterval.into())
+    }
+
+    /// Batches the schedule may still run, bounded by `max_executions` and `end_at`.
+    pub fn remaining_executions(&self) -> u64 {
+        let past_end = self
+            .end_at
+            .is_some_and(|end_at| self.next_execution > end_at);
+        if past_end {
+            return 0;
+        }
+        self.max_executions.map_or(u64::MAX, |max_executions| {
+            max_executions.saturating_sub(self.last_executed_batch)
+        })
+    }
+
+    /// Whether no further batch can run: the end time, execution count or reserve is exhausted.
+    pub fn is_finished(&self) -> bool {
+        self.remaining_executions() == 0 || self.reserved_amount < self.per_execution_amount
+    }
+
+    /// Whether the schedule's end time has passed; nothing is paid out after it.
+    pub fn has_ended(&self, current_time: u64) -> bool {
+        self.end_at.is_some_and(|end_at| current_time > end_at)
+    }
+
+    /// Whether the current batch has been open for `timeout_secs`, counted from its first
+    /// claim or, if nobody has claimed yet, from its due time.
+    pub fn batch_timed_out(&self, current_time: u64, timeout_secs: u64) -> bool {
+        let opened_at = if self.batch_start_time > 0 {
+            self.batch_start_time
+        } else {
+            self.next_execution
+        };
+        current_time >= opened_at.saturating_add(timeout_secs)
+    }
+
+    /// Token amount paid for a leaf value under the schedule's payout mode.
+    pub fn leaf_payout(&self, value: u64) -> Result<u64> {
+        payout_for(self.payout_mode, self.per_execution_amount, value)
+    }
+
+    /// Leaves that are either paid this batch or withheld, i.e. no longer awaiting a claim.
+    pub fn settled_leaf_count(&self) -> u16 {
+        self.paid_bitmap
+            .iter()
+            .zip(self.withheld_bitmap.iter())
+            .map(|(paid, withheld)| (paid | withheld).count_ones() as u16)
+            .sum()
+    }
+
+    /// Swaps in a staged recipient set, if any. Only call between batches.
+    pub fn apply_pending_update(&mut self) -> bool {
+        let Some(merkle_root) = self.pending_merkle_root.take() else {
+            return false;
+        };
+
+        self.merkle_root = merkle_root;
+        self.total_recipients = self.pending_total_recipients;
+        self.per_execution_amount = self.pending_per_execution_amount;
+        self.withheld_bitmap = [0u8; 128];
+        self.pending_total_recipients = 0;
+        self.pending_per_execution_amount = 0;
+
+        true
+    }
+
+    /// Marks the schedule completed and returns its remaining reserve to `vault.available`.
+    pub fn complete(&mut self, vault: &mut VaultAccount) -> Result<u64> {
+        let released_amount = self.reserved_amount;
+
+        vault.reserved = vault
+            .reserved
+            .checked_sub(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+        vault.available = vault
+            .available
+            .checked_add(released_amount)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+
+        self.reserved_amount = 0;
+        self.status = ScheduleStatus::Completed;
+
+        Ok(released_amount)
+    }
+}
+
+/// Resolves the first due time from an optional start and checks both ends against the clock.
+pub fn first_execution_time(
+    current_time: u64,
+    interval_secs: u64,
+    start_at: Option<u64>,
+    end_at: Option<u64>,
+) -> Result<u64> {
+    let next_execution = match start_at {
+        Some(start_at) => {
+            require!(start_at >= current_time, VeilProgramError::InvalidStartTime);
+            start_at
+        }
+        None => current_time
+            .checked_add(interval_secs)
+            .ok_or(VeilProgramError::InvalidScheduleInterval)?,
+    };
+
+    if let Some(end_at) = end_at {
+        require!(end_at >= next_execution, VeilProgramError::InvalidEndTime);
+    }
+
+    Ok(next_execution)
+}
+
+/// Token amount for a leaf value: the value itself, or its bps share of `per_execution_amount`
+/// rounded down.
+pub fn payout_for(mode: PayoutMode, per_execution_amount: u64, value: u64) -> Result<u64> {
+    match mode {
+        PayoutMode::Amount => Ok(value),
+        PayoutMode::Bps => {
+            require!(value <= BPS_DENOMINATOR, VeilProgramError::InvalidBps);
+            let share = (per_execution_amount as u128)
+                .checked_mul(value as u128)
+                .ok_or(VeilProgramError::InsufficientFunds)?
+                / BPS_DENOMINATOR as u128;
+            u64::try_from(share).map_err(|_| VeilProgramError::InsufficientFunds.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval_schedule(policy: MissedPeriodPolicy) -> ScheduleAccount {
+        ScheduleAccount::init(
+            Pubkey::default(),
+            Pubkey::default(),
+            ScheduleCadence {
+                kind: ScheduleKind::Interval,
+                interval_secs: 100,
+                next_execution: 1_000,
+                missed_period_policy: policy,
+                end_at: None,
+                per_execution_amount: 0,
+                max_executions: None,
+            },
+            0,
+            [0u8; 32],
+            1,
+            [0u8; 32],
+            0,
+        )
+    }
+
+    #[test]
+    fn on_time_settlement_moves_one_period_under_either_policy() {
+        for policy in [MissedPeriodPolicy::Skip, MissedPeriodPolicy::CatchUp] {
+            let schedule = interval_schedule(policy);
+            assert_eq!(schedule.next_due_time(1_050).unwrap(), 1_100);
+        }
+    }
+
+    #[test]
+    fn late_settlement_with_skip_jumps_past_missed_periods() {
+        let schedule = interval_schedule(MissedPeriodPolicy::Skip);
+        assert_eq!(schedule.next_due_time(1_350).unwrap(), 1_400);
+        // A settlement landing on a due time still moves strictly past it
+        assert_eq!(schedule.next_due_time(1_300).unwrap(), 1_400);
+    }
+
+    #[test]
+    fn late_settlement_with_catch_up_keeps_missed_periods() {
+        let mut schedule = interval_schedule(MissedPeriodPolicy::CatchUp);
+        assert_eq!(schedule.next_due_time(1_350).unwrap(), 1_100);
+
+        // Each following settlement steps one period along the grid
+        schedule.next_execution = 1_100;
+        assert_eq!(schedule.next_due_time(1_360).unwrap(), 1_200);
+    }
+
+    #[test]
+    fn unclaimed_batch_times_out_from_its_due_time() {
+        let schedule = interval_schedule(MissedPeriodPolicy::Skip);
+        assert!(!schedule.batch_timed_out(1_499, 500));
+        assert!(schedule.batch_timed_out(1_500, 500));
+    }
+
+    #[test]
+    fn claimed_batch_times_out_from_its_first_claim() {
+        let mut schedule = interval_schedule(MissedPeriodPolicy::Skip);
+        schedule.batch_start_time = 1_200;
+        assert!(!schedule.batch_timed_out(1_500, 500));
+        assert!(schedule.batch_timed_out(1_700, 500));
+    }
+
+    #[test]
+    fn schedule_ends_strictly_after_end_at() {
+        let mut schedule = interval_schedule(MissedPeriodPolicy::Skip);
+        assert!(!schedule.has_ended(u64::MAX));
+        schedule.end_at = Some(1_300);
+        assert!(!schedule.has_ended(1_300));
+        assert!(schedule.has_ended(1_301));
+    }
+
+    #[test]
+    fn due_times_stay_on_the_anchor_grid() {
+        let mut schedule = interval_schedule(MissedPeriodPolicy::Skip);
+        schedule.next_execution = 1_170; // off-grid, e.g. after an update
+        assert_eq!(schedule.next_due_time(1_180).unwrap(), 1_200);
+    }
 }
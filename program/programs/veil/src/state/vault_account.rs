@@ -1,12 +1,206 @@
 use anchor_lang::prelude::*;
 
+use crate::error::VeilProgramError;
+use crate::{
+   MAX_ALLOWED_DESTINATIONS, MAX_ALLOWED_FUNDERS, MAX_APPROVERS, WITHDRAW_LIMIT_LOOSEN_DELAY_SECS,
+};
+
 #[account]
 #[derive(InitSpace)]
 pub struct VaultAccount {
    pub employer: Pubkey,
+   pub seed_owner: Pubkey, // employer at init; vault PDA seeds stay on this key across transfers
+   pub pending_employer: Option<Pubkey>, // set by transfer_vault_ownership until accepted
    pub vault_ata: Pubkey,  // PDA token account
    pub token_mint: Pubkey, // USDC
    pub available: u64,     // free funds
    pub reserved: u64,      // locked funds
+   pub fees_owed: u64,     // protocol fees a claim couldn't cover from available yet
+   pub tip_budget: u64,    // lamports set aside for keeper tips
+   pub funder_allowlist_enabled: bool,
+   #[max_len(8)]
+   pub allowed_funders: Vec<Pubkey>, // third parties allowed to deposit_for
+   pub organization: Option<Pubkey>,
+   pub destination_allowlist_enabled: bool,
+   #[max_len(8)]
+   pub allowed_destinations: Vec<Pubkey>, // token accounts withdraw may pay out to
+   pub withdraw_limit: u64,        // max withdrawn per window (0 = no limit)
+   pub withdraw_window_secs: u64,
+   pub window_start: u64,
+   pub window_spent: u64,          // withdrawn since window_start
+   pub pending_withdraw_limit: u64, // staged loosening, applied at pending_limit_effective_at
+   pub pending_withdraw_window_secs: u64,
+   pub pending_limit_effective_at: u64, // 0 = nothing staged
+   #[max_len(8)]
+   pub approvers: Vec<Pubkey>,
+   pub approval_threshold: u8, // approvals needed for sensitive actions (0 = employer alone)
+   pub action_nonce: u64,      // seeds the next PendingAction
    pub bump: u8,
 }
+
+impl VaultAccount {
+   pub fn is_funder_allowed(&self, funder: &Pubkey) -> bool {
+      *funder == self.employer
+         || !self.funder_allowlist_enabled
+         || self.allowed_funders.iter().any(|allowed| allowed == funder)
+   }
+
+   pub fn is_destination_allowed(&self, destination: &Pubkey) -> bool {
+      !self.destination_allowlist_enabled
+         || self.allowed_destinations.iter().any(|allowed| allowed == destination)
+   }
+
+   /// Whether `limit` per `window_secs` is at least as strict as the current limit.
+   pub fn is_tighter_limit(&self, limit: u64, window_secs: u64) -> bool {
+      limit != 0
+         && (self.withdraw_limit == 0
+            || (limit <= self.withdraw_limit && window_secs >= self.withdraw_window_secs))
+   }
+
+   /// Applies a tighter limit now, or stages a looser one behind the loosening delay.
+   /// Returns when the new limit takes effect.
+   pub fn set_withdraw_limit(&mut self, limit: u64, window_secs: u64, now: u64) -> Result<u64> {
+      if self.is_tighter_limit(limit, window_secs) {
+         self.withdraw_limit = limit;
+         self.withdraw_window_secs = window_secs;
+         self.clear_pending_withdraw_limit();
+         return Ok(now);
+      }
+
+      let effective_at = now
+         .checked_add(WITHDRAW_LIMIT_LOOSEN_DELAY_SECS)
+         .ok_or(VeilProgramError::InvalidWithdrawLimit)?;
+      self.pending_withdraw_limit = limit;
+      self.pending_withdraw_window_secs = window_secs;
+      self.pending_limit_effective_at = effective_at;
+
+      Ok(effective_at)
+   }
+
+   /// Swaps in a staged limit once its delay has passed.
+   pub fn apply_pending_withdraw_limit(&mut self, now: u64) {
+      if self.pending_limit_effective_at == 0 || now < self.pending_limit_effective_at {
+         return;
+      }
+
+      self.withdraw_limit = self.pending_withdraw_limit;
+      self.withdraw_window_secs = self.pending_withdraw_window_secs;
+      self.clear_pending_withdraw_limit();
+   }
+
+   /// Counts a withdrawal against the current window, starting a new window if the last one ended.
+   /// `default_limit` (limit, window_secs) applies when the vault has no limit of its own.
+   pub fn record_withdrawal(
+      &mut self,
+      amount: u64,
+      now: u64,
+      default_limit: (u64, u64),
+   ) -> Result<()> {
+      self.apply_pending_withdraw_limit(now);
+      let (withdraw_limit, window_secs) = if self.withdraw_limit == 0 {
+         default_limit
+      } else {
+         (self.withdraw_limit, self.withdraw_window_secs)
+      };
+      if withdraw_limit == 0 {
+         return Ok(());
+      }
+
+      let window_end = self.window_start.saturating_add(window_secs);
+      if now >= window_end {
+         self.window_start = now;
+         self.window_spent = 0;
+      }
+
+      let spent = self
+         .window_spent
+         .checked_add(amount)
+         .ok_or(VeilProgramError::WithdrawLimitExceeded)?;
+      if spent > withdraw_limit {
+         return Err(VeilProgramError::WithdrawLimitExceeded.into());
+      }
+      self.window_spent = spent;
+
+      Ok(())
+   }
+
+   fn clear_pending_withdraw_limit(&mut self) {
+      self.pending_withdraw_limit = 0;
+      self.pending_withdraw_window_secs = 0;
+      self.pending_limit_effective_at = 0;
+   }
+}
+
+pub fn validate_funder_allowlist(allowed_funders: &[Pubkey], allowlist_enabled: bool) -> Result<()> {
+   validate_allowlist(
+      allowed_funders,
+      allowlist_enabled,
+      MAX_ALLOWED_FUNDERS,
+      VeilProgramError::TooManyAllowedFunders,
+      VeilProgramError::InvalidFunderAllowlist,
+      VeilProgramError::DuplicateAllowedFunder,
+   )
+}
+
+pub fn validate_destination_allowlist(
+   allowed_destinations: &[Pubkey],
+   allowlist_enabled: bool,
+) -> Result<()> {
+   validate_allowlist(
+      allowed_destinations,
+      allowlist_enabled,
+      MAX_ALLOWED_DESTINATIONS,
+      VeilProgramError::TooManyAllowedDestinations,
+      VeilProgramError::InvalidDestinationAllowlist,
+      VeilProgramError::DuplicateAllowedDestination,
+   )
+}
+
+pub fn validate_approvers(approvers: &[Pubkey], threshold: u8) -> Result<()> {
+   validate_allowlist(
+      approvers,
+      threshold > 0,
+      MAX_APPROVERS,
+      VeilProgramError::TooManyApprovers,
+      VeilProgramError::InvalidApprovers,
+      VeilProgramError::DuplicateApprover,
+   )?;
+   require!(
+      threshold as usize <= approvers.len(),
+      VeilProgramError::InvalidApprovers
+   );
+
+   Ok(())
+}
+
+pub(crate) fn validate_allowlist(
+   entries: &[Pubkey],
+   enabled: bool,
+   max_len: usize,
+   too_many: VeilProgramError,
+   invalid: VeilProgramError,
+   duplicate: VeilProgramError,
+) -> Result<()> {
+   if entries.len() > max_len {
+      return Err(too_many.into());
+   }
+   if enabled && entries.is_empty() {
+      return Err(invalid.into());
+   }
+
+   for (index, entry) in entries.iter().enumerate() {
+      if *entry == Pubkey::default() {
+         return Err(invalid.into());
+      }
+
+      let has_duplicate = entries
+         .iter()
+         .skip(index + 1)
+         .any(|other_entry| other_entry == entry);
+      if has_duplicate {
+         return Err(duplicate.into());
+      }
+   }
+
+   Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::state::ScheduleStatus;
+use crate::ANCHOR_DISCRIMINATOR;
+
+/// Every open schedule of a vault, seeded by (vault), so clients can list them in one read.
+/// The account is resized as schedules are created and closed.
+#[account]
+pub struct VaultScheduleIndex {
+    pub vault: Pubkey,
+    pub entries: Vec<ScheduleIndexEntry>,
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct ScheduleIndexEntry {
+    pub schedule_id: [u8; 32],
+    pub schedule: Pubkey,
+    pub status: ScheduleStatus, // as of the last create, pause, cancel, base-layer settle or sync
+}
+
+impl VaultScheduleIndex {
+    /// Account size holding `entries` schedules
+    pub fn space(entries: usize) -> usize {
+        ANCHOR_DISCRIMINATOR + 32 + 4 + entries * ScheduleIndexEntry::INIT_SPACE + 1
+    }
+
+    pub fn add(&mut self, schedule_id: [u8; 32], schedule: Pubkey) {
+        self.entries.push(ScheduleIndexEntry {
+            schedule_id,
+            schedule,
+            status: ScheduleStatus::Active,
+        });
+    }
+
+    pub fn set_status(&mut self, schedule: &Pubkey, status: ScheduleStatus) -> Result<()> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.schedule == *schedule)
+            .ok_or(VeilProgramError::ScheduleNotIndexed)?;
+        entry.status = status;
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, schedule: &Pubkey) -> Result<()> {
+        let position = self
+            .entries
+            .iter()
+            .position(|entry| entry.schedule == *schedule)
+            .ok_or(VeilProgramError::ScheduleNotIndexed)?;
+        self.entries.remove(position);
+
+        Ok(())
+    }
+}
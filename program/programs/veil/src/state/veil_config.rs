@@ -14,6 +14,8 @@ pub struct VeilConfig {
     #[max_len(16)]
     pub allowed_mints: Vec<Pubkey>,
     pub batch_timeout_secs: u64, // Global timeout for batch completion (seconds)
+    pub fee_bps: u16,            // Protocol fee charged on each claim, on top of the payout
+    pub treasury: Pubkey,        // Owner of the token accounts that receive fees
 }
 
 impl VeilConfig {
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+
+/// Vesting terms a vesting leaf commits to.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct VestingGrant {
+    pub total: u64,
+    pub cliff_ts: u64, // Nothing vests before this time
+    pub start_ts: u64,
+    pub end_ts: u64,      // Fully vested from this time
+    pub period_secs: u64, // Unlock step between start_ts and end_ts
+}
+
+impl VestingGrant {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.start_ts < self.end_ts
+                && (self.start_ts..=self.end_ts).contains(&self.cliff_ts)
+                && self.period_secs > 0
+                && self.period_secs <= self.end_ts - self.start_ts,
+            VeilProgramError::InvalidVestingGrant
+        );
+        Ok(())
+    }
+
+    /// Amount vested at `current_time`, unlocked in whole periods after the cliff.
+    pub fn vested_amount(&self, current_time: u64) -> Result<u64> {
+        if current_time < self.cliff_ts {
+            return Ok(0);
+        }
+        if current_time >= self.end_ts {
+            return Ok(self.total);
+        }
+
+        let periods = current_time.saturating_sub(self.start_ts) / self.period_secs;
+        let unlocked_secs = periods * self.period_secs;
+        let vested = (self.total as u128)
+            .checked_mul(unlocked_secs as u128)
+            .ok_or(VeilProgramError::InsufficientFunds)?
+            / (self.end_ts - self.start_ts) as u128;
+        u64::try_from(vested).map_err(|_| VeilProgramError::InsufficientFunds.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1_200 over 1_200s in 100s steps, with a cliff three periods in
+    fn grant() -> VestingGrant {
+        VestingGrant {
+            total: 1_200,
+            cliff_ts: 1_300,
+            start_ts: 1_000,
+            end_ts: 2_200,
+            period_secs: 100,
+        }
+    }
+
+    #[test]
+    fn nothing_vests_before_the_cliff() {
+        let grant = grant();
+        assert_eq!(grant.vested_amount(900).unwrap(), 0);
+        assert_eq!(grant.vested_amount(1_299).unwrap(), 0);
+    }
+
+    #[test]
+    fn cliff_unlocks_the_periods_accrued_since_start() {
+        assert_eq!(grant().vested_amount(1_300).unwrap(), 300);
+    }
+
+    #[test]
+    fn mid_period_only_counts_whole_periods() {
+        let grant = grant();
+        assert_eq!(grant.vested_amount(1_750).unwrap(), 700);
+        assert_eq!(grant.vested_amount(1_799).unwrap(), 700);
+        assert_eq!(grant.vested_amount(1_800).unwrap(), 800);
+    }
+
+    #[test]
+    fn fully_vested_from_the_end() {
+        let grant = grant();
+        assert_eq!(grant.vested_amount(2_200).unwrap(), 1_200);
+        assert_eq!(grant.vested_amount(10_000).unwrap(), 1_200);
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction;
+use anchor_lang::system_program;
+
+use crate::error::VeilProgramError;
+
+/// Create a program-owned PDA whose rent is taken from a program-owned `funder`
+/// (e.g. a schedule's rent budget) rather than from a signer. Like Anchor's `init`, a PDA
+/// someone already sent lamports to is only topped up to rent exemption. Returns the
+/// lamports taken from `funder`.
+pub fn create_pda_from_budget<'info>(
+    funder: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<u64> {
+    require!(
+        new_account.data_is_empty() && new_account.owner == &system_program::ID,
+        VeilProgramError::AccountAlreadyExists
+    );
+
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(new_account.lamports());
+    if lamports > 0 {
+        **funder.try_borrow_mut_lamports()? = funder
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(VeilProgramError::InsufficientRentBudget)?;
+        **new_account.try_borrow_mut_lamports()? = new_account
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(VeilProgramError::InsufficientFunds)?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(new_account.key, space as u64),
+        &[new_account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(new_account.key, &crate::ID),
+        &[new_account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+
+    Ok(lamports)
+}
+
+/// Close a program-owned account, sending its lamports to `destination`.
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<u64> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&system_program::ID);
+    account.realloc(0, false)?;
+
+    Ok(lamports)
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::ActionExecuted;
+use crate::utils::close_program_account;
+use crate::{PendingAction, VaultAccount, VaultAction};
+
+/// Gate for actions that need approver sign-off. A no-op while the vault has no approval
+/// threshold; otherwise `pending_action` must hold exactly `action` with enough approvals,
+/// and is closed to `employer` once consumed.
+pub fn consume_approved_action<'info>(
+    vault: &Account<'info, VaultAccount>,
+    pending_action: Option<&Account<'info, PendingAction>>,
+    employer: &AccountInfo<'info>,
+    action: VaultAction,
+) -> Result<()> {
+    if vault.approval_threshold == 0 {
+        return Ok(());
+    }
+
+    let pending_action = pending_action.ok_or(VeilProgramError::ApprovalRequired)?;
+    require!(
+        pending_action.vault == vault.key(),
+        VeilProgramError::VaultMismatch
+    );
+    require!(
+        pending_action.action == action,
+        VeilProgramError::ActionMismatch
+    );
+    require!(
+        pending_action.approval_count(vault) >= vault.approval_threshold,
+        VeilProgramError::NotEnoughApprovals
+    );
+
+    close_program_account(&pending_action.to_account_info(), employer)?;
+
+    emit!(ActionExecuted {
+        vault: vault.key(),
+        pending_action: pending_action.key(),
+        nonce: pending_action.nonce,
+    });
+
+    Ok(())
+}
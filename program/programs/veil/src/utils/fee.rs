@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::error::VeilProgramError;
+use crate::events::{FeeCollected, KeeperTipped};
+use crate::utils::transfer_from_vault;
+use crate::{VaultAccount, VeilConfig, BPS_DENOMINATOR};
+
+/// Charge the protocol fee for a payout. The fee comes out of `vault.available`, on top of
+/// the payout, and goes to the treasury's token account. Whatever `available` can't cover is
+/// added to `vault.fees_owed` and paid with the next fee, so a low balance never blocks
+/// payroll. Returns the fee charged.
+pub fn collect_fee<'info>(
+    config: &VeilConfig,
+    vault: &mut Account<'info, VaultAccount>,
+    vault_ata: &Account<'info, TokenAccount>,
+    treasury_ata: Option<&Account<'info, TokenAccount>>,
+    token_program: &Program<'info, Token>,
+    schedule: Pubkey,
+    payout: u64,
+) -> Result<u64> {
+    let fee = (payout as u128)
+        .checked_mul(config.fee_bps as u128)
+        .ok_or(VeilProgramError::InsufficientFunds)?
+        / BPS_DENOMINATOR as u128;
+    let fee = u64::try_from(fee).map_err(|_| VeilProgramError::InsufficientFunds)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+
+    let treasury_ata = treasury_ata.ok_or(VeilProgramError::InvalidTreasury)?;
+    require!(
+        treasury_ata.owner == config.treasury,
+        VeilProgramError::InvalidTreasury
+    );
+    require!(
+        treasury_ata.mint == vault.token_mint,
+        VeilProgramError::InvalidMint
+    );
+
+    let due = vault
+        .fees_owed
+        .checked_add(fee)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    let paid = due.min(vault.available);
+    vault.available -= paid;
+    vault.fees_owed = due - paid;
+
+    if paid > 0 {
+        transfer_from_vault(
+            vault,
+            vault_ata,
+            treasury_ata.to_account_info(),
+            token_program,
+            paid,
+        )?;
+    }
+
+    emit!(FeeCollected {
+        schedule,
+        vault: vault.key(),
+        treasury: config.treasury,
+        payout,
+        fee,
+        paid,
+        fees_owed: vault.fees_owed,
+        available: vault.available,
+    });
+
+    Ok(fee)
+}
+
+/// Pay a keeper tip in lamports from `vault.tip_budget` to the claim signer. Skipped when
+/// the budget can't cover it, so a drained budget never blocks payroll. Returns the tip paid.
+pub fn pay_keeper_tip<'info>(
+    vault: &mut Account<'info, VaultAccount>,
+    keeper: &AccountInfo<'info>,
+    schedule: Pubkey,
+    tip: u64,
+) -> Result<u64> {
+    if tip == 0 || vault.tip_budget < tip {
+        return Ok(0);
+    }
+
+    vault.tip_budget -= tip;
+    let vault_info = vault.to_account_info();
+    **vault_info.try_borrow_mut_lamports()? -= tip;
+    **keeper.try_borrow_mut_lamports()? += tip;
+
+    emit!(KeeperTipped {
+        schedule,
+        vault: vault.key(),
+        keeper: keeper.key(),
+        lamports: tip,
+        tip_budget: vault.tip_budget,
+    });
+
+    Ok(tip)
+}
@@ -1,15 +1,63 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::hash::hash;
 
+use crate::state::VestingGrant;
+
+/// Part of a leaf's amount routed to another token account (e.g. tax or benefits)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub struct PaymentSplit {
+    pub destination: Pubkey, // Token account that receives this part
+    pub amount: u64,
+}
+
 /// Hash a leaf node: hash(recipient_pubkey || amount)
 pub fn hash_leaf(recipient: &Pubkey, amount: u64) -> [u8; 32] {
-    let mut data = Vec::with_capacity(40);
+    hash_leaf_with_splits(recipient, amount, &[])
+}
+
+/// Hash a leaf node with splits: hash(recipient_pubkey || amount || (destination || amount)*).
+/// With no splits this is the same as `hash_leaf`.
+pub fn hash_leaf_with_splits(recipient: &Pubkey, amount: u64, splits: &[PaymentSplit]) -> [u8; 32] {
+    let mut data = Vec::with_capacity(40 + splits.len() * 40);
     data.extend_from_slice(recipient.as_ref());
     data.extend_from_slice(&amount.to_le_bytes());
+    for split in splits {
+        data.extend_from_slice(split.destination.as_ref());
+        data.extend_from_slice(&split.amount.to_le_bytes());
+    }
+    hash(&data).to_bytes()
+}
+
+/// Hash a stream leaf: hash(recipient_pubkey || rate_per_sec || start_time || end_time)
+pub fn hash_stream_leaf(
+    recipient: &Pubkey,
+    rate_per_sec: u64,
+    start_time: u64,
+    end_time: u64,
+) -> [u8; 32] {
+    let mut data = Vec::with_capacity(56);
+    data.extend_from_slice(recipient.as_ref());
+    data.extend_from_slice(&rate_per_sec.to_le_bytes());
+    data.extend_from_slice(&start_time.to_le_bytes());
+    data.extend_from_slice(&end_time.to_le_bytes());
+    hash(&data).to_bytes()
+}
+
+/// Hash a vesting leaf:
+/// hash(recipient_pubkey || total || cliff_ts || start_ts || end_ts || period_secs)
+pub fn hash_vesting_leaf(recipient: &Pubkey, grant: &VestingGrant) -> [u8; 32] {
+    let mut data = Vec::with_capacity(72);
+    data.extend_from_slice(recipient.as_ref());
+    data.extend_from_slice(&grant.total.to_le_bytes());
+    data.extend_from_slice(&grant.cliff_ts.to_le_bytes());
+    data.extend_from_slice(&grant.start_ts.to_le_bytes());
+    data.extend_from_slice(&grant.end_ts.to_le_bytes());
+    data.extend_from_slice(&grant.period_secs.to_le_bytes());
     hash(&data).to_bytes()
 }
 
 /// Verify a Merkle proof for a given leaf
+#[allow(clippy::manual_is_multiple_of)]
 pub fn verify_merkle_proof(leaf: [u8; 32], proof: &[[u8; 32]], index: u16, root: [u8; 32]) -> bool {
     let mut computed = leaf;
     let mut idx = index as usize;
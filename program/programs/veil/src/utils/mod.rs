@@ -1,3 +1,13 @@
+pub mod accounts;
+pub mod approvals;
+pub mod fee;
 pub mod merkle;
+pub mod settlement;
+pub mod token;
 
+pub use accounts::*;
+pub use approvals::*;
+pub use fee::*;
 pub use merkle::*;
+pub use settlement::*;
+pub use token::*;
@@ -0,0 +1,281 @@
+use anchor_lang::prelude::*;
+
+use crate::error::VeilProgramError;
+use crate::events::{
+    BatchRecordSkipped, BatchRecorded, DustReleased, ScheduleCompleted, ScheduleRefilled,
+    ScheduleUnderfunded, ScheduleUpdateApplied,
+};
+use crate::state::{ScheduleKind, ScheduleStatus};
+use crate::utils::create_pda_from_budget;
+use crate::{BatchRecord, ScheduleAccount, VaultAccount, VaultScheduleIndex, ANCHOR_DISCRIMINATOR};
+
+/// Closes the current batch: releases its dust, holds back unclaimed pay, moves the
+/// schedule to its next due time and completes it when it has nothing left to run.
+/// Claims have already taken what they paid out of the reserve. The vault's schedule index
+/// is only passed on the base layer; in the rollup it is synced after the fact.
+#[allow(clippy::too_many_arguments)]
+pub fn settle_batch<'info>(
+    schedule: &mut Account<'info, ScheduleAccount>,
+    vault: &mut Account<'info, VaultAccount>,
+    schedule_index: Option<&mut Account<'info, VaultScheduleIndex>>,
+    batch_record: Option<&UncheckedAccount<'info>>,
+    system_program: Option<&Program<'info, System>>,
+    current_time: u64,
+    timestamp: i64,
+) -> Result<()> {
+    if schedule.batch_dust > 0 {
+        release_dust(schedule, vault, timestamp)?;
+    }
+
+    if schedule.claim_window_secs > 0 {
+        hold_back_unclaimed(schedule, batch_record, system_program, current_time)?;
+    }
+
+    // Reset paid_count, bitmap, batch_start_time, and advance next_execution
+    // along the anchor grid so late settlements don't shift future pay dates
+    schedule.paid_count = 0;
+    schedule.paid_bitmap = [0u8; 128]; // Clear bitmap
+    schedule.batch_paid_amount = 0;
+    schedule.batch_paid_bps = 0;
+    schedule.batch_dust = 0;
+    schedule.batch_start_time = 0; // Reset for next batch
+    if schedule.kind == ScheduleKind::Interval {
+        schedule.next_execution = schedule.next_due_time(current_time)?;
+    }
+    apply_pending_update(schedule, timestamp);
+    schedule.last_executed_batch = schedule
+        .last_executed_batch
+        .checked_add(1)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+
+    if schedule.auto_refill_cycles > 0 && schedule.remaining_executions() > 0 {
+        refill_reserve(schedule, vault, timestamp)?;
+    }
+
+    // Release whatever is left once no further batch can run
+    if schedule.is_finished() {
+        let released_amount = schedule.complete(vault)?;
+        if let Some(schedule_index) = schedule_index {
+            schedule_index.set_status(&schedule.key(), ScheduleStatus::Completed)?;
+        }
+
+        emit!(ScheduleCompleted {
+            employer: vault.employer,
+            schedule: schedule.key(),
+            released_amount,
+            executed_batches: schedule.last_executed_batch,
+            timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Returns this batch's rounding dust from the schedule reserve to `vault.available`.
+fn release_dust(
+    schedule: &mut Account<ScheduleAccount>,
+    vault: &mut Account<VaultAccount>,
+    timestamp: i64,
+) -> Result<()> {
+    let dust = schedule.batch_dust.min(schedule.reserved_amount);
+
+    schedule.reserved_amount = schedule
+        .reserved_amount
+        .checked_sub(dust)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    vault.reserved = vault
+        .reserved
+        .checked_sub(dust)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    vault.available = vault
+        .available
+        .checked_add(dust)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+
+    emit!(DustReleased {
+        schedule: schedule.key(),
+        vault: vault.key(),
+        amount: dust,
+        timestamp,
+    });
+
+    Ok(())
+}
+
+/// Moves this batch's unclaimed pay out of the schedule reserve into a `BatchRecord`,
+/// where it stays claimable through `claim_past_batch` until the window expires. If no
+/// record can be created the pay is left in the reserve and `BatchRecordSkipped` is emitted.
+fn hold_back_unclaimed<'info>(
+    schedule: &mut Account<'info, ScheduleAccount>,
+    batch_record: Option<&UncheckedAccount<'info>>,
+    system_program: Option<&Program<'info, System>>,
+    current_time: u64,
+) -> Result<()> {
+    let held_amount = schedule
+        .per_execution_amount
+        .saturating_sub(schedule.batch_paid_amount)
+        .saturating_sub(schedule.batch_dust)
+        .min(schedule.reserved_amount);
+    if held_amount == 0 {
+        return Ok(());
+    }
+
+    let schedule_key = schedule.key();
+    let batch = schedule
+        .last_executed_batch
+        .checked_add(1)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    let space = ANCHOR_DISCRIMINATOR + BatchRecord::INIT_SPACE;
+
+    // Without a record account or the rent for one, the unclaimed pay stays in the
+    // schedule reserve instead of blocking settlement
+    let record_accounts = match (batch_record, system_program) {
+        (Some(batch_record), Some(system_program)) => {
+            let rent = Rent::get()?
+                .minimum_balance(space)
+                .saturating_sub(batch_record.lamports());
+            (rent <= schedule.rent_budget).then_some((batch_record, system_program))
+        }
+        _ => None,
+    };
+    let Some((batch_record, system_program)) = record_accounts else {
+        emit!(BatchRecordSkipped {
+            schedule: schedule_key,
+            batch,
+            unclaimed_amount: held_amount,
+            rent_budget: schedule.rent_budget,
+        });
+        return Ok(());
+    };
+
+    let batch_bytes = batch.to_le_bytes();
+    let (record_pda, bump) = Pubkey::find_program_address(
+        &[b"batch_record", schedule_key.as_ref(), batch_bytes.as_ref()],
+        &crate::ID,
+    );
+    require!(
+        batch_record.key() == record_pda,
+        VeilProgramError::InvalidBatchRecord
+    );
+
+    let lamports = create_pda_from_budget(
+        &schedule.to_account_info(),
+        &batch_record.to_account_info(),
+        &system_program.to_account_info(),
+        space,
+        &[
+            b"batch_record",
+            schedule_key.as_ref(),
+            batch_bytes.as_ref(),
+            &[bump],
+        ],
+    )?;
+    schedule.rent_budget = schedule
+        .rent_budget
+        .checked_sub(lamports)
+        .ok_or(VeilProgramError::InsufficientRentBudget)?;
+
+    let expires_at = current_time
+        .checked_add(schedule.claim_window_secs)
+        .ok_or(VeilProgramError::InvalidClaimWindow)?;
+    let record = BatchRecord {
+        schedule: schedule_key,
+        batch,
+        merkle_root: schedule.merkle_root,
+        total_recipients: schedule.total_recipients,
+        payout_mode: schedule.payout_mode,
+        per_execution_amount: schedule.per_execution_amount,
+        paid_bitmap: schedule.paid_bitmap,
+        withheld_bitmap: schedule.withheld_bitmap,
+        held_amount,
+        expires_at,
+        bump,
+    };
+    record.try_serialize(&mut &mut batch_record.try_borrow_mut_data()?[..])?;
+
+    // Still counted in vault.reserved until claimed or released by close_batch_record
+    schedule.reserved_amount = schedule
+        .reserved_amount
+        .checked_sub(held_amount)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    schedule.open_batch_records = schedule
+        .open_batch_records
+        .checked_add(1)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+
+    emit!(BatchRecorded {
+        schedule: schedule_key,
+        batch_record: record_pda,
+        batch,
+        held_amount,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+/// Applies a staged recipient set at a batch boundary.
+pub fn apply_pending_update(schedule: &mut Account<ScheduleAccount>, timestamp: i64) {
+    if schedule.apply_pending_update() {
+        emit!(ScheduleUpdateApplied {
+            schedule: schedule.key(),
+            merkle_root: schedule.merkle_root,
+            total_recipients: schedule.total_recipients,
+            per_execution_amount: schedule.per_execution_amount,
+            batch: schedule.last_executed_batch,
+            timestamp,
+        });
+    }
+}
+
+/// Tops the reserve back up to `auto_refill_cycles` worth of batches from `vault.available`.
+fn refill_reserve(
+    schedule: &mut Account<ScheduleAccount>,
+    vault: &mut Account<VaultAccount>,
+    timestamp: i64,
+) -> Result<()> {
+    let target_cycles = (schedule.auto_refill_cycles as u64).min(schedule.remaining_executions());
+    let target_amount = schedule
+        .per_execution_amount
+        .checked_mul(target_cycles)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    let shortfall = target_amount.saturating_sub(schedule.reserved_amount);
+    if shortfall == 0 {
+        return Ok(());
+    }
+
+    let refill_amount = shortfall.min(vault.available);
+    vault.available = vault
+        .available
+        .checked_sub(refill_amount)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    vault.reserved = vault
+        .reserved
+        .checked_add(refill_amount)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+    schedule.reserved_amount = schedule
+        .reserved_amount
+        .checked_add(refill_amount)
+        .ok_or(VeilProgramError::InsufficientFunds)?;
+
+    if refill_amount == shortfall {
+        emit!(ScheduleRefilled {
+            schedule: schedule.key(),
+            vault: vault.key(),
+            amount: refill_amount,
+            reserved_amount: schedule.reserved_amount,
+            timestamp,
+        });
+    } else {
+        emit!(ScheduleUnderfunded {
+            schedule: schedule.key(),
+            vault: vault.key(),
+            refilled_amount: refill_amount,
+            missing_amount: shortfall - refill_amount,
+            reserved_amount: schedule.reserved_amount,
+            timestamp,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::error::VeilProgramError;
+use crate::utils::PaymentSplit;
+use crate::{VaultAccount, MAX_PAYMENT_SPLITS};
+
+/// Transfer tokens out of `vault_ata`, signed by the vault PDA.
+pub fn transfer_from_vault<'info>(
+    vault: &Account<'info, VaultAccount>,
+    vault_ata: &Account<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let seed_owner = vault.seed_owner;
+    let token_mint_key = vault.token_mint;
+    let bump = vault.bump;
+    let seeds = &[
+        b"vault",
+        seed_owner.as_ref(),
+        token_mint_key.as_ref(),
+        &[bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: vault_ata.to_account_info(),
+        to,
+        authority: vault.to_account_info(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, amount)
+}
+
+/// Pay a leaf's `amount` out of the vault: each split goes to its destination account
+/// (passed in the same order in `split_accounts`) and the remainder to `recipient_ata`.
+pub fn transfer_with_splits<'info>(
+    vault: &Account<'info, VaultAccount>,
+    vault_ata: &Account<'info, TokenAccount>,
+    recipient_ata: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+    splits: &[PaymentSplit],
+    split_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    require!(
+        splits.len() <= MAX_PAYMENT_SPLITS,
+        VeilProgramError::TooManySplits
+    );
+    require!(
+        split_accounts.len() == splits.len(),
+        VeilProgramError::InvalidSplit
+    );
+
+    let mut remaining = amount;
+    for (split, destination) in splits.iter().zip(split_accounts) {
+        require!(
+            split.amount > 0 && destination.key() == split.destination,
+            VeilProgramError::InvalidSplit
+        );
+        remaining = remaining
+            .checked_sub(split.amount)
+            .ok_or(VeilProgramError::InvalidSplit)?;
+        transfer_from_vault(
+            vault,
+            vault_ata,
+            destination.clone(),
+            token_program,
+            split.amount,
+        )?;
+    }
+
+    if remaining > 0 {
+        transfer_from_vault(vault, vault_ata, recipient_ata, token_program, remaining)?;
+    }
+
+    Ok(())
+}
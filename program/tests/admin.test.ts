@@ -423,4 +423,78 @@ describe("Admin Instructions", () => {
             }
         });
     });
+
+    describe("set_protocol_fee", () => {
+        const treasury = Keypair.generate().publicKey;
+
+        before(async () => {
+            await ensureConfigInitialized(ctx);
+            await ensureUnpaused(ctx);
+        });
+
+        it("Should update the protocol fee and treasury", async () => {
+            await ctx.program.methods
+                .setProtocolFee(50, treasury)
+                .accountsPartial({ governance: ctx.governance.publicKey })
+                .signers([ctx.governance])
+                .rpc();
+
+            const updatedConfig = await ctx.program.account.veilConfig.fetch(ctx.configPda);
+            expect(updatedConfig.feeBps).to.equal(50);
+            expect(updatedConfig.treasury.toString()).to.equal(treasury.toString());
+        });
+
+        it("Should clear the protocol fee", async () => {
+            await ctx.program.methods
+                .setProtocolFee(0, PublicKey.default)
+                .accountsPartial({ governance: ctx.governance.publicKey })
+                .signers([ctx.governance])
+                .rpc();
+
+            const updatedConfig = await ctx.program.account.veilConfig.fetch(ctx.configPda);
+            expect(updatedConfig.feeBps).to.equal(0);
+        });
+
+        it("Should fail above the maximum fee", async () => {
+            try {
+                await ctx.program.methods
+                    .setProtocolFee(501, treasury)
+                    .accountsPartial({ governance: ctx.governance.publicKey })
+                    .signers([ctx.governance])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidFeeBps");
+            }
+        });
+
+        it("Should fail with a fee and no treasury", async () => {
+            try {
+                await ctx.program.methods
+                    .setProtocolFee(50, PublicKey.default)
+                    .accountsPartial({ governance: ctx.governance.publicKey })
+                    .signers([ctx.governance])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidTreasury");
+            }
+        });
+
+        it("Should fail if unauthorized", async () => {
+            try {
+                await ctx.program.methods
+                    .setProtocolFee(50, treasury)
+                    .accountsPartial({ governance: unauthorizedUser.publicKey })
+                    .signers([unauthorizedUser])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("Unauthorized");
+            }
+        });
+    });
 });
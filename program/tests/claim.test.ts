@@ -0,0 +1,1353 @@
+import { expect } from "chai";
+import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
+import { BN, Idl, Program } from "@coral-xyz/anchor";
+import {
+    createAssociatedTokenAccount,
+    getAccount,
+    getAssociatedTokenAddress,
+    TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { buildMerkleTree, hashStreamLeaf, Recipient } from "../../sdk/src/merkle";
+import sdkIdl from "../../sdk/src/idl/idl.json";
+import {
+    createTestContext,
+    ensureConfigInitialized,
+    ensureErAuthority,
+    ensureUnpaused,
+    setupEmployerWithTokens,
+    TestContext,
+    getVaultPda,
+    getVaultAtaPda,
+    getSchedulePda,
+    getBatchRecordPda,
+    getReceiptPda,
+    getLeafClaimPda,
+    getScheduleIndexPda,
+    getChainTime,
+    waitForChainTime,
+    randomId,
+    getErrorCode,
+    getEvents,
+    findEvent,
+} from "./helpers";
+
+describe("Claim Instructions", () => {
+    const INTERVAL_SECS = 3600;
+    let ctx: TestContext;
+    let employer: Keypair;
+    let vaultPda: PublicKey;
+    let vaultAtaPda: PublicKey;
+
+    interface DueSchedule {
+        scheduleId: number[];
+        schedulePda: PublicKey;
+        proofs: number[][][];
+    }
+
+    async function newRecipient(): Promise<{ keypair: Keypair; ata: PublicKey }> {
+        const keypair = Keypair.generate();
+        const ata = await createAssociatedTokenAccount(
+            ctx.provider.connection,
+            ctx.admin.payer,
+            ctx.allowedMint,
+            keypair.publicKey
+        );
+        return { keypair, ata };
+    }
+
+    // Interval schedule whose first batch is due a couple of seconds from now
+    async function createDueSchedule(
+        recipients: Recipient[],
+        perExecutionAmount: number,
+        reservedAmount: number,
+        endAfterSecs: number | null = null,
+        maxExecutions: number | null = null
+    ): Promise<DueSchedule> {
+        const { root, proofs } = buildMerkleTree(recipients);
+        const scheduleId = randomId();
+        const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+        const startAt = (await getChainTime(ctx.provider.connection)) + 2;
+
+        await ctx.program.methods
+            .createSchedule(
+                scheduleId,
+                new BN(INTERVAL_SECS),
+                new BN(reservedAmount),
+                new BN(perExecutionAmount),
+                Array.from(root),
+                recipients.length,
+                randomId(),
+                { skip: {} },
+                new BN(startAt),
+                endAfterSecs === null ? null : new BN(startAt + endAfterSecs),
+                maxExecutions === null ? null : new BN(maxExecutions)
+            )
+            .accountsPartial({
+                authority: employer.publicKey,
+                vault: vaultPda,
+            })
+            .signers([employer])
+            .rpc();
+
+        await waitForChainTime(ctx.provider.connection, startAt);
+
+        return {
+            scheduleId,
+            schedulePda,
+            proofs: proofs.map(({ proof }) => proof.map((node) => Array.from(node))),
+        };
+    }
+
+    function claim(
+        schedule: DueSchedule,
+        recipient: Recipient,
+        recipientAta: PublicKey,
+        leafIndex: number,
+        receipt: PublicKey | null = null,
+        scheduleIndex: PublicKey | null = null
+    ) {
+        return ctx.program.methods
+            .claimPayment(
+                schedule.scheduleId,
+                recipient.address,
+                new BN(recipient.amount.toString()),
+                leafIndex,
+                schedule.proofs[leafIndex],
+                (recipient.splits ?? []).map((split) => ({
+                    destination: split.destination,
+                    amount: new BN(split.amount.toString()),
+                }))
+            )
+            .accountsPartial({
+                erAuthority: ctx.erAuthority.publicKey,
+                vault: vaultPda,
+                vaultAta: vaultAtaPda,
+                schedule: schedule.schedulePda,
+                recipientAta,
+                tokenMint: ctx.allowedMint,
+                batchRecord: null,
+                systemProgram: receipt ? SystemProgram.programId : null,
+                receipt,
+                treasuryAta: null,
+                scheduleIndex,
+            })
+            .remainingAccounts(
+                (recipient.splits ?? []).map((split) => ({
+                    pubkey: split.destination,
+                    isWritable: true,
+                    isSigner: false,
+                }))
+            )
+            .signers([ctx.erAuthority]);
+    }
+
+    function settle(
+        scheduleId: number[],
+        schedulePda: PublicKey,
+        batchRecord: PublicKey | null = null
+    ) {
+        return ctx.program.methods
+            .settleBatch(scheduleId)
+            .accountsPartial({
+                caller: ctx.erAuthority.publicKey,
+                vault: vaultPda,
+                schedule: schedulePda,
+                batchRecord,
+                systemProgram: batchRecord ? SystemProgram.programId : null,
+            })
+            .signers([ctx.erAuthority]);
+    }
+
+    // Sends lamports to a PDA before the program creates it, as anyone could
+    async function preFund(pda: PublicKey, lamports: number) {
+        await ctx.provider.sendAndConfirm(
+            new Transaction().add(
+                SystemProgram.transfer({
+                    fromPubkey: ctx.admin.publicKey,
+                    toPubkey: pda,
+                    lamports,
+                })
+            )
+        );
+    }
+
+    before(async () => {
+        ctx = await createTestContext();
+        await ensureConfigInitialized(ctx);
+        await ensureUnpaused(ctx);
+        await ensureErAuthority(ctx);
+
+        employer = Keypair.generate();
+        await ctx.provider.connection.confirmTransaction(
+            await ctx.provider.connection.requestAirdrop(employer.publicKey, 2 * 1e9),
+            "confirmed"
+        );
+
+        [vaultPda] = getVaultPda(employer.publicKey, ctx.allowedMint);
+        [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+        await ctx.program.methods
+            .initVault()
+            .accountsPartial({
+                employer: employer.publicKey,
+                tokenMint: ctx.allowedMint,
+            })
+            .signers([employer])
+            .rpc();
+
+        const employerAta = await setupEmployerWithTokens(ctx, employer.publicKey, 1_000_000);
+        await ctx.program.methods
+            .deposit(new BN(500_000))
+            .accountsPartial({
+                employer: employer.publicKey,
+                vaultAta: vaultAtaPda,
+                employerAta,
+                tokenMint: ctx.allowedMint,
+            })
+            .signers([employer])
+            .rpc();
+    });
+
+    describe("claim_payment with an SDK-built tree", () => {
+        let plain: { keypair: Keypair; ata: PublicKey };
+        let split: { keypair: Keypair; ata: PublicKey };
+        let savings: { keypair: Keypair; ata: PublicKey };
+        let garnishment: { keypair: Keypair; ata: PublicKey };
+        let recipients: Recipient[];
+        let schedule: DueSchedule;
+
+        before(async () => {
+            [plain, split, savings, garnishment] = await Promise.all([
+                newRecipient(),
+                newRecipient(),
+                newRecipient(),
+                newRecipient(),
+            ]);
+            recipients = [
+                { address: plain.keypair.publicKey, amount: BigInt(1_000) },
+                {
+                    address: split.keypair.publicKey,
+                    amount: BigInt(2_000),
+                    splits: [
+                        { destination: savings.ata, amount: BigInt(300) },
+                        { destination: garnishment.ata, amount: BigInt(200) },
+                    ],
+                },
+                { address: Keypair.generate().publicKey, amount: BigInt(500) },
+            ];
+            schedule = await createDueSchedule(recipients, 3_500, 7_000);
+        });
+
+        it("Should accept a proof for a leaf hashed by the SDK", async () => {
+            await claim(schedule, recipients[0], plain.ata, 0).rpc();
+
+            const balance = Number((await getAccount(ctx.provider.connection, plain.ata)).amount);
+            expect(balance).to.equal(1_000);
+        });
+
+        it("Should pay every split destination and the remainder to the recipient", async () => {
+            await claim(schedule, recipients[1], split.ata, 1).rpc();
+
+            const balanceOf = async (ata: PublicKey) =>
+                Number((await getAccount(ctx.provider.connection, ata)).amount);
+            expect(await balanceOf(savings.ata)).to.equal(300);
+            expect(await balanceOf(garnishment.ata)).to.equal(200);
+            expect(await balanceOf(split.ata)).to.equal(1_500);
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.paidCount).to.equal(2);
+            expect(after.batchPaidAmount.toNumber()).to.equal(3_000);
+        });
+    });
+
+    describe("withhold_recipient / settle_batch", () => {
+        let first: { keypair: Keypair; ata: PublicKey };
+        let second: { keypair: Keypair; ata: PublicKey };
+        let recipients: Recipient[];
+        let schedule: DueSchedule;
+
+        before(async () => {
+            first = await newRecipient();
+            second = await newRecipient();
+            recipients = [
+                { address: first.keypair.publicKey, amount: BigInt(1_000) },
+                { address: second.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            schedule = await createDueSchedule(recipients, 2_000, 4_000);
+
+            await claim(schedule, recipients[0], first.ata, 0).rpc();
+        });
+
+        it("Should fail to settle while a leaf is still claimable", async () => {
+            try {
+                await settle(schedule.scheduleId, schedule.schedulePda).rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("BatchNotSettleable");
+            }
+        });
+
+        it("Should reject the claim of a withheld leaf", async () => {
+            await ctx.program.methods
+                .withholdRecipient(1, true)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            try {
+                await claim(schedule, recipients[1], second.ata, 1).rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("RecipientWithheld");
+            }
+
+            const balance = Number((await getAccount(ctx.provider.connection, second.ata)).amount);
+            expect(balance).to.equal(0);
+        });
+
+        it("Should settle a batch whose last unpaid leaf is withheld", async () => {
+            const before = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+
+            await settle(schedule.scheduleId, schedule.schedulePda).rpc();
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.lastExecutedBatch.toNumber()).to.equal(1);
+            expect(after.paidCount).to.equal(0);
+            expect(after.batchStartTime.toNumber()).to.equal(0);
+            expect(after.nextExecution.toNumber()).to.equal(
+                before.nextExecution.toNumber() + INTERVAL_SECS
+            );
+            expect(after.reservedAmount.toNumber()).to.equal(3_000);
+            // The hold carries over to the next batch
+            expect(after.withheldBitmap[0] & 0b10).to.equal(0b10);
+        });
+    });
+
+    describe("reconcile_vault mid-batch", () => {
+        it("Should reconcile while a batch is partially claimed", async () => {
+            const first = await newRecipient();
+            const second = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: first.keypair.publicKey, amount: BigInt(1_000) },
+                { address: second.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 2_000, 4_000);
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await claim(schedule, recipients[0], first.ata, 0).rpc();
+
+            // The claimed pay left vault_ata and the reserve together
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.paidCount).to.equal(1);
+            expect(after.reservedAmount.toNumber()).to.equal(3_000);
+
+            await ctx.program.methods
+                .reconcileVault()
+                .accountsPartial({
+                    caller: employer.publicKey,
+                    vault: vaultPda,
+                    vaultAta: vaultAtaPda,
+                })
+                .signers([employer])
+                .rpc();
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.reserved.toNumber()).to.equal(vaultBefore.reserved.toNumber() - 1_000);
+            const vaultAtaBalance = Number(
+                (await getAccount(ctx.provider.connection, vaultAtaPda)).amount
+            );
+            expect(vaultAtaBalance).to.equal(
+                vaultAfter.available.toNumber() + vaultAfter.reserved.toNumber()
+            );
+        });
+    });
+
+    describe("pre-funded PDAs", () => {
+        it("Should create a batch record someone already sent lamports to", async () => {
+            const first = await newRecipient();
+            const second = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: first.keypair.publicKey, amount: BigInt(1_000) },
+                { address: second.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 2_000, 4_000);
+
+            await ctx.program.methods
+                .setClaimWindow(new BN(7 * 86400))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+            await ctx.program.methods
+                .fundRentBudget(new BN(10_000_000))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            await claim(schedule, recipients[0], first.ata, 0).rpc();
+            await ctx.program.methods
+                .withholdRecipient(1, true)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            const [batchRecordPda] = getBatchRecordPda(schedule.schedulePda, 1);
+            await preFund(batchRecordPda, 1_000);
+
+            await settle(schedule.scheduleId, schedule.schedulePda, batchRecordPda).rpc();
+
+            const record = await ctx.program.account.batchRecord.fetch(batchRecordPda);
+            expect(record.batch.toNumber()).to.equal(1);
+            expect(record.heldAmount.toNumber()).to.equal(1_000);
+
+            // Only the shortfall to rent exemption came out of the rent budget
+            const rent = await ctx.provider.connection.getMinimumBalanceForRentExemption(
+                (await ctx.provider.connection.getAccountInfo(batchRecordPda))!.data.length
+            );
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.rentBudget.toNumber()).to.equal(10_000_000 - (rent - 1_000));
+        });
+
+        it("Should create a payment receipt someone already sent lamports to", async () => {
+            const recipient = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: recipient.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 1_000, 3_000);
+
+            await ctx.program.methods
+                .setReceiptMode(true)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+            await ctx.program.methods
+                .fundRentBudget(new BN(10_000_000))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            const [receiptPda] = getReceiptPda(schedule.schedulePda, 1, 0);
+            await preFund(receiptPda, 1_000);
+
+            await claim(schedule, recipients[0], recipient.ata, 0, receiptPda).rpc();
+
+            const receipt = await ctx.program.account.paymentReceipt.fetch(receiptPda);
+            expect(receipt.batch.toNumber()).to.equal(1);
+            expect(receipt.leafIndex).to.equal(0);
+            expect(receipt.recipient.toBase58()).to.equal(recipient.keypair.publicKey.toBase58());
+            expect(receipt.amount.toNumber()).to.equal(1_000);
+        });
+
+        it("Should create a stream leaf claim someone already sent lamports to", async () => {
+            const recipient = await newRecipient();
+            const now = await getChainTime(ctx.provider.connection);
+            const [ratePerSec, startTime, endTime] = [10, now - 100, now + 1_000];
+            // Single-leaf tree: the root is the leaf hash and the proof is empty
+            const root = hashStreamLeaf(
+                recipient.keypair.publicKey,
+                BigInt(ratePerSec),
+                BigInt(startTime),
+                BigInt(endTime)
+            );
+            const scheduleId = randomId();
+            const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+
+            await ctx.program.methods
+                .createStreamSchedule(scheduleId, new BN(20_000), Array.from(root), 1, randomId())
+                .accountsPartial({
+                    authority: employer.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employer])
+                .rpc();
+            await ctx.program.methods
+                .fundRentBudget(new BN(10_000_000))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            const [leafClaimPda] = getLeafClaimPda(schedulePda, 0);
+            await preFund(leafClaimPda, 1_000);
+
+            await ctx.program.methods
+                .claimStream(
+                    scheduleId,
+                    recipient.keypair.publicKey,
+                    new BN(ratePerSec),
+                    new BN(startTime),
+                    new BN(endTime),
+                    0,
+                    []
+                )
+                .accountsPartial({
+                    claimer: recipient.keypair.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                    leafClaim: leafClaimPda,
+                    recipientAta: recipient.ata,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([recipient.keypair])
+                .rpc();
+
+            const balance = Number(
+                (await getAccount(ctx.provider.connection, recipient.ata)).amount
+            );
+            expect(balance).to.be.greaterThan(0);
+            const leafClaim = await ctx.program.account.leafClaim.fetch(leafClaimPda);
+            expect(leafClaim.claimed.toNumber()).to.equal(balance);
+        });
+    });
+
+    describe("claim window", () => {
+        async function setClaimWindow(schedule: DueSchedule, secs: number) {
+            await ctx.program.methods
+                .setClaimWindow(new BN(secs))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+        }
+
+        it("Should settle without a record when the rent budget can't pay for one", async () => {
+            const recipient = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: recipient.keypair.publicKey, amount: BigInt(1_000) },
+                { address: Keypair.generate().publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 2_000, 4_000);
+            await setClaimWindow(schedule, 7 * 86400);
+
+            await claim(schedule, recipients[0], recipient.ata, 0).rpc();
+            await ctx.program.methods
+                .withholdRecipient(1, true)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            const [batchRecordPda] = getBatchRecordPda(schedule.schedulePda, 1);
+            const signature = await settle(
+                schedule.scheduleId,
+                schedule.schedulePda,
+                batchRecordPda
+            ).rpc();
+
+            const skipped = findEvent(await getEvents(ctx.program, signature), "BatchRecordSkipped");
+            expect(skipped).to.not.be.undefined;
+            expect(skipped!.data.unclaimedAmount.toNumber()).to.equal(1_000);
+            expect(await ctx.provider.connection.getAccountInfo(batchRecordPda)).to.be.null;
+
+            // The batch still closed, and the unclaimed pay stayed in the reserve
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.lastExecutedBatch.toNumber()).to.equal(1);
+            expect(after.reservedAmount.toNumber()).to.equal(3_000);
+            expect(after.openBatchRecords).to.equal(0);
+        });
+
+        describe("claim_past_batch / close_batch_record", () => {
+            const WINDOW_SECS = 8;
+            let recipients: Recipient[];
+            let late: { keypair: Keypair; ata: PublicKey };
+            let schedule: DueSchedule;
+            let batchRecordPda: PublicKey;
+
+            before(async () => {
+                const onTime = await newRecipient();
+                late = await newRecipient();
+                recipients = [
+                    { address: onTime.keypair.publicKey, amount: BigInt(1_000) },
+                    { address: late.keypair.publicKey, amount: BigInt(1_000) },
+                    { address: Keypair.generate().publicKey, amount: BigInt(1_000) },
+                ];
+                schedule = await createDueSchedule(recipients, 3_000, 3_000, 4);
+                await setClaimWindow(schedule, WINDOW_SECS);
+                await ctx.program.methods
+                    .fundRentBudget(new BN(10_000_000))
+                    .accountsPartial({
+                        employer: employer.publicKey,
+                        vault: vaultPda,
+                        schedule: schedule.schedulePda,
+                    })
+                    .signers([employer])
+                    .rpc();
+
+                await claim(schedule, recipients[0], onTime.ata, 0).rpc();
+
+                // Once the schedule has ended its open batch can be settled
+                const { endAt } = await ctx.program.account.scheduleAccount.fetch(
+                    schedule.schedulePda
+                );
+                await waitForChainTime(ctx.provider.connection, endAt.toNumber() + 1);
+                [batchRecordPda] = getBatchRecordPda(schedule.schedulePda, 1);
+                await settle(schedule.scheduleId, schedule.schedulePda, batchRecordPda).rpc();
+            });
+
+            it("Should hold the unclaimed pay in a batch record", async () => {
+                const record = await ctx.program.account.batchRecord.fetch(batchRecordPda);
+                expect(record.heldAmount.toNumber()).to.equal(2_000);
+
+                const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+                expect(after.status).to.deep.equal({ completed: {} });
+                expect(after.openBatchRecords).to.equal(1);
+            });
+
+            it("Should pay a leaf from the batch record inside the window", async () => {
+                await ctx.program.methods
+                    .claimPastBatch(
+                        schedule.scheduleId,
+                        new BN(1),
+                        recipients[1].address,
+                        new BN(1_000),
+                        1,
+                        schedule.proofs[1],
+                        []
+                    )
+                    .accountsPartial({
+                        erAuthority: ctx.erAuthority.publicKey,
+                        vault: vaultPda,
+                        vaultAta: vaultAtaPda,
+                        schedule: schedule.schedulePda,
+                        batchRecord: batchRecordPda,
+                        recipientAta: late.ata,
+                        tokenMint: ctx.allowedMint,
+                        treasuryAta: null,
+                    })
+                    .signers([ctx.erAuthority])
+                    .rpc();
+
+                const balance = Number((await getAccount(ctx.provider.connection, late.ata)).amount);
+                expect(balance).to.equal(1_000);
+                const record = await ctx.program.account.batchRecord.fetch(batchRecordPda);
+                expect(record.heldAmount.toNumber()).to.equal(1_000);
+            });
+
+            it("Should close the record after the window and release what is left", async () => {
+                const record = await ctx.program.account.batchRecord.fetch(batchRecordPda);
+                await waitForChainTime(ctx.provider.connection, record.expiresAt.toNumber());
+                const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+                await ctx.program.methods
+                    .closeBatchRecord()
+                    .accountsPartial({
+                        caller: ctx.erAuthority.publicKey,
+                        vault: vaultPda,
+                        schedule: schedule.schedulePda,
+                        batchRecord: batchRecordPda,
+                    })
+                    .signers([ctx.erAuthority])
+                    .rpc();
+
+                expect(await ctx.provider.connection.getAccountInfo(batchRecordPda)).to.be.null;
+                const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+                expect(vaultAfter.available.toNumber()).to.equal(
+                    vaultBefore.available.toNumber() + 1_000
+                );
+                expect(vaultAfter.reserved.toNumber()).to.equal(
+                    vaultBefore.reserved.toNumber() - 1_000
+                );
+
+                const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+                expect(after.openBatchRecords).to.equal(0);
+                expect(after.rentBudget.toNumber()).to.equal(10_000_000);
+            });
+        });
+    });
+
+    describe("end_at", () => {
+        let recipient: { keypair: Keypair; ata: PublicKey };
+        let recipients: Recipient[];
+        let schedule: DueSchedule;
+
+        before(async () => {
+            recipient = await newRecipient();
+            recipients = [{ address: recipient.keypair.publicKey, amount: BigInt(1_000) }];
+            schedule = await createDueSchedule(recipients, 1_000, 3_000, 1);
+            const { endAt } = await ctx.program.account.scheduleAccount.fetch(
+                schedule.schedulePda
+            );
+            await waitForChainTime(ctx.provider.connection, endAt.toNumber() + 1);
+        });
+
+        it("Should reject a claim after the schedule's end time", async () => {
+            try {
+                await claim(schedule, recipients[0], recipient.ata, 0).rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ScheduleEnded");
+            }
+
+            const balance = Number((await getAccount(ctx.provider.connection, recipient.ata)).amount);
+            expect(balance).to.equal(0);
+        });
+
+        it("Should complete the schedule and release its reserve once settled", async () => {
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await settle(schedule.scheduleId, schedule.schedulePda).rpc();
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.status).to.deep.equal({ completed: {} });
+            expect(after.reservedAmount.toNumber()).to.equal(0);
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(
+                vaultBefore.available.toNumber() + 3_000
+            );
+            expect(vaultAfter.reserved.toNumber()).to.equal(
+                vaultBefore.reserved.toNumber() - 3_000
+            );
+        });
+    });
+
+    describe("basis-point leaves", () => {
+        // 1_001 split 3333/3333/3334 bps leaves two base units of rounding dust
+        const PER_EXECUTION = 1_001;
+        let payees: Array<{ keypair: Keypair; ata: PublicKey }>;
+        let recipients: Recipient[];
+        let schedule: DueSchedule;
+
+        before(async () => {
+            payees = [await newRecipient(), await newRecipient(), await newRecipient()];
+            recipients = payees.map(({ keypair }, index) => ({
+                address: keypair.publicKey,
+                amount: BigInt(index === 2 ? 3_334 : 3_333),
+            }));
+            schedule = await createDueSchedule(recipients, PER_EXECUTION, PER_EXECUTION * 3);
+
+            // The payout mode can only change while paused between batches
+            const pause = (paused: boolean) =>
+                ctx.program.methods
+                    .pauseSchedule(paused)
+                    .accountsPartial({
+                        authority: employer.publicKey,
+                        vault: vaultPda,
+                        schedule: schedule.schedulePda,
+                    })
+                    .signers([employer])
+                    .rpc();
+            await pause(true);
+            await ctx.program.methods
+                .setPayoutMode({ bps: {} })
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+            await pause(false);
+        });
+
+        it("Should pay each leaf its share of the per-execution amount", async () => {
+            await claim(schedule, recipients[0], payees[0].ata, 0).rpc();
+            await claim(schedule, recipients[1], payees[1].ata, 1).rpc();
+
+            for (const { ata } of payees.slice(0, 2)) {
+                const balance = Number((await getAccount(ctx.provider.connection, ata)).amount);
+                expect(balance).to.equal(333);
+            }
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.batchPaidBps.toNumber()).to.equal(6_666);
+            expect(after.batchPaidAmount.toNumber()).to.equal(666);
+            expect(after.batchDust.toNumber()).to.equal(1);
+        });
+
+        it("Should release the rounding dust when the batch settles", async () => {
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            const signature = await claim(schedule, recipients[2], payees[2].ata, 2).rpc();
+
+            const balance = Number(
+                (await getAccount(ctx.provider.connection, payees[2].ata)).amount
+            );
+            expect(balance).to.equal(333);
+
+            const released = findEvent(await getEvents(ctx.program, signature), "DustReleased");
+            expect(released).to.not.be.undefined;
+            expect(released!.data.amount.toNumber()).to.equal(2);
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.lastExecutedBatch.toNumber()).to.equal(1);
+            expect(after.batchDust.toNumber()).to.equal(0);
+            expect(after.reservedAmount.toNumber()).to.equal(PER_EXECUTION * 2);
+
+            // The dust goes back to available rather than staying reserved
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(vaultBefore.available.toNumber() + 2);
+            expect(vaultAfter.reserved.toNumber()).to.equal(
+                vaultBefore.reserved.toNumber() - 335
+            );
+        });
+    });
+
+    describe("staged recipient update", () => {
+        it("Should apply the staged tree when the current batch closes", async () => {
+            const first = await newRecipient();
+            const second = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: first.keypair.publicKey, amount: BigInt(1_000) },
+                { address: second.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 2_000, 6_000);
+            await claim(schedule, recipients[0], first.ata, 0).rpc();
+
+            const { root: newRoot } = buildMerkleTree([
+                { address: first.keypair.publicKey, amount: BigInt(1_000) },
+                { address: second.keypair.publicKey, amount: BigInt(1_000) },
+                { address: Keypair.generate().publicKey, amount: BigInt(1_000) },
+            ]);
+            await ctx.program.methods
+                .stageScheduleUpdate(Array.from(newRoot), 3, new BN(3_000))
+                .accountsPartial({
+                    authority: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+            const before = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+
+            // The open batch is still paid against the old tree
+            const signature = await claim(schedule, recipients[1], second.ata, 1).rpc();
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.merkleRoot).to.deep.equal(Array.from(newRoot));
+            expect(after.totalRecipients).to.equal(3);
+            expect(after.perExecutionAmount.toNumber()).to.equal(3_000);
+            expect(after.pendingMerkleRoot).to.be.null;
+            expect(after.pendingTotalRecipients).to.equal(0);
+            expect(after.pendingPerExecutionAmount.toNumber()).to.equal(0);
+            // Applying the update doesn't move the cadence: the next batch is one interval on
+            expect(after.anchorTime.toNumber()).to.equal(before.anchorTime.toNumber());
+            expect(after.nextExecution.toNumber()).to.equal(
+                before.nextExecution.toNumber() + INTERVAL_SECS
+            );
+
+            const applied = findEvent(
+                await getEvents(ctx.program, signature),
+                "ScheduleUpdateApplied"
+            );
+            expect(applied).to.not.be.undefined;
+            expect(applied!.data.batch.toNumber()).to.equal(0);
+        });
+    });
+
+    describe("max_executions", () => {
+        const indexedStatus = async (schedulePda: PublicKey) => {
+            const index = await ctx.program.account.vaultScheduleIndex.fetch(
+                getScheduleIndexPda(vaultPda)[0]
+            );
+            return index.entries.find((e) => e.schedule.equals(schedulePda))!.status;
+        };
+
+        it("Should complete after its last batch and release the remaining reserve", async () => {
+            const first = await newRecipient();
+            const second = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: first.keypair.publicKey, amount: BigInt(1_000) },
+                { address: second.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            // Reserve enough for three batches but cap the schedule at one
+            const schedule = await createDueSchedule(recipients, 2_000, 6_000, null, 1);
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await claim(schedule, recipients[0], first.ata, 0).rpc();
+            const midBatch = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(midBatch.status).to.deep.equal({ active: {} });
+
+            // The claim that settles the capped batch completes the schedule
+            await claim(
+                schedule,
+                recipients[1],
+                second.ata,
+                1,
+                null,
+                getScheduleIndexPda(vaultPda)[0]
+            ).rpc();
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.status).to.deep.equal({ completed: {} });
+            expect(await indexedStatus(schedule.schedulePda)).to.deep.equal({ completed: {} });
+            expect(after.lastExecutedBatch.toNumber()).to.equal(1);
+            expect(after.reservedAmount.toNumber()).to.equal(0);
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(
+                vaultBefore.available.toNumber() + 4_000
+            );
+            expect(vaultAfter.reserved.toNumber()).to.equal(
+                vaultBefore.reserved.toNumber() - 6_000
+            );
+        });
+
+        it("Should catch the index up through sync_schedule_index", async () => {
+            const recipient = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: recipient.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 1_000, 1_000, null, 1);
+
+            // As in the rollup, the completing claim leaves the index out
+            await claim(schedule, recipients[0], recipient.ata, 0).rpc();
+            expect(await indexedStatus(schedule.schedulePda)).to.deep.equal({ active: {} });
+
+            const signature = await ctx.program.methods
+                .syncScheduleIndex()
+                .accountsPartial({
+                    caller: ctx.admin.publicKey,
+                    scheduleIndex: getScheduleIndexPda(vaultPda)[0],
+                    schedule: schedule.schedulePda,
+                })
+                .rpc();
+
+            expect(await indexedStatus(schedule.schedulePda)).to.deep.equal({ completed: {} });
+            const synced = findEvent(await getEvents(ctx.program, signature), "ScheduleIndexSynced");
+            expect(synced!.data.status).to.deep.equal({ completed: {} });
+        });
+    });
+
+    describe("auto-refill at settlement", () => {
+        let underfunded: DueSchedule | null = null;
+
+        async function enableAutoRefill(schedule: DueSchedule, cycles: number) {
+            await ctx.program.methods
+                .setAutoRefill(cycles)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+        }
+
+        after(async () => {
+            // Hand the reserve the underfunded schedule took back to the vault
+            if (underfunded) {
+                await ctx.program.methods
+                    .cancelSchedule()
+                    .accountsPartial({
+                        authority: employer.publicKey,
+                        vault: vaultPda,
+                        schedule: underfunded.schedulePda,
+                    })
+                    .signers([employer])
+                    .rpc();
+            }
+        });
+
+        it("Should top the reserve back up to the target cycles", async () => {
+            const recipient = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: recipient.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 1_000, 1_000);
+            await enableAutoRefill(schedule, 3);
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            const signature = await claim(schedule, recipients[0], recipient.ata, 0).rpc();
+
+            const refilled = findEvent(await getEvents(ctx.program, signature), "ScheduleRefilled");
+            expect(refilled).to.not.be.undefined;
+            expect(refilled!.data.amount.toNumber()).to.equal(3_000);
+            expect(refilled!.data.reservedAmount.toNumber()).to.equal(3_000);
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.status).to.deep.equal({ active: {} });
+            expect(after.reservedAmount.toNumber()).to.equal(3_000);
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(
+                vaultBefore.available.toNumber() - 3_000
+            );
+        });
+
+        it("Should refill what the vault has and report the shortfall", async () => {
+            const recipient = await newRecipient();
+            // A quarter of what is available per batch, so 24 cycles can't be covered
+            const available = (await ctx.program.account.vaultAccount.fetch(vaultPda)).available;
+            const perBatch = Math.floor(available.toNumber() / 4);
+            const recipients: Recipient[] = [
+                { address: recipient.keypair.publicKey, amount: BigInt(perBatch) },
+            ];
+            underfunded = await createDueSchedule(recipients, perBatch, perBatch);
+            await enableAutoRefill(underfunded, 24);
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            const signature = await claim(underfunded, recipients[0], recipient.ata, 0).rpc();
+
+            const events = await getEvents(ctx.program, signature);
+            expect(findEvent(events, "ScheduleRefilled")).to.be.undefined;
+            const shortfall = findEvent(events, "ScheduleUnderfunded");
+            expect(shortfall).to.not.be.undefined;
+            expect(shortfall!.data.refilledAmount.toNumber()).to.equal(
+                vaultBefore.available.toNumber()
+            );
+            expect(shortfall!.data.missingAmount.toNumber()).to.equal(
+                24 * perBatch - vaultBefore.available.toNumber()
+            );
+
+            // The claim itself still went through
+            const balance = Number((await getAccount(ctx.provider.connection, recipient.ata)).amount);
+            expect(balance).to.equal(perBatch);
+
+            const after = await ctx.program.account.scheduleAccount.fetch(underfunded.schedulePda);
+            expect(after.status).to.deep.equal({ active: {} });
+            expect(after.reservedAmount.toNumber()).to.equal(vaultBefore.available.toNumber());
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(0);
+        });
+    });
+
+    describe("keeper tips", () => {
+        const TIP = 5_000;
+        let recipients: Recipient[];
+        let payees: Array<{ keypair: Keypair; ata: PublicKey }>;
+        let schedule: DueSchedule;
+
+        async function setKeeperTip(target: DueSchedule, lamports: number) {
+            await ctx.program.methods
+                .setKeeperTip(new BN(lamports))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: target.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+        }
+
+        before(async () => {
+            payees = [await newRecipient(), await newRecipient()];
+            recipients = payees.map(({ keypair }) => ({
+                address: keypair.publicKey,
+                amount: BigInt(1_000),
+            }));
+            schedule = await createDueSchedule(recipients, 2_000, 4_000);
+            await setKeeperTip(schedule, TIP);
+
+            // Enough for both claims of the batch, and only part of a third tip
+            await ctx.program.methods
+                .fundTipBudget(new BN(TIP * 2 + TIP / 2))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employer])
+                .rpc();
+        });
+
+        after(async () => {
+            const { tipBudget } = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            await ctx.program.methods
+                .withdrawTipBudget(tipBudget)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employer])
+                .rpc();
+        });
+
+        it("Should tip the keeper from the tip budget on each claim", async () => {
+            for (const leafIndex of [0, 1]) {
+                const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+                const lamportsBefore = await ctx.provider.connection.getBalance(vaultPda);
+
+                // The second claim is the one that settles the batch
+                const signature = await claim(
+                    schedule,
+                    recipients[leafIndex],
+                    payees[leafIndex].ata,
+                    leafIndex
+                ).rpc();
+
+                const tipped = findEvent(await getEvents(ctx.program, signature), "KeeperTipped");
+                expect(tipped).to.not.be.undefined;
+                expect(tipped!.data.keeper.toBase58()).to.equal(
+                    ctx.erAuthority.publicKey.toBase58()
+                );
+                expect(tipped!.data.lamports.toNumber()).to.equal(TIP);
+
+                const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+                expect(vaultAfter.tipBudget.toNumber()).to.equal(
+                    vaultBefore.tipBudget.toNumber() - TIP
+                );
+                expect(await ctx.provider.connection.getBalance(vaultPda)).to.equal(
+                    lamportsBefore - TIP
+                );
+                // Tips are lamports and never touch the token reserve
+                expect(vaultAfter.reserved.toNumber()).to.equal(
+                    vaultBefore.reserved.toNumber() - 1_000
+                );
+            }
+
+            const after = await ctx.program.account.scheduleAccount.fetch(schedule.schedulePda);
+            expect(after.lastExecutedBatch.toNumber()).to.equal(1);
+        });
+
+        it("Should skip the tip without failing the claim when the budget runs short", async () => {
+            const payee = await newRecipient();
+            const shortRecipients: Recipient[] = [
+                { address: payee.keypair.publicKey, amount: BigInt(1_000) },
+            ];
+            const shortSchedule = await createDueSchedule(shortRecipients, 1_000, 2_000);
+            await setKeeperTip(shortSchedule, TIP);
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultBefore.tipBudget.toNumber()).to.be.lessThan(TIP);
+
+            const signature = await claim(shortSchedule, shortRecipients[0], payee.ata, 0).rpc();
+
+            const tipped = findEvent(await getEvents(ctx.program, signature), "KeeperTipped");
+            expect(tipped).to.be.undefined;
+            const balance = Number((await getAccount(ctx.provider.connection, payee.ata)).amount);
+            expect(balance).to.equal(1_000);
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.tipBudget.toNumber()).to.equal(vaultBefore.tipBudget.toNumber());
+        });
+    });
+
+    describe("settle_batch on an unclaimed one-shot", () => {
+        it("Should keep the reserve locked until the batch timeout has passed", async () => {
+            const recipient = await newRecipient();
+            const { root } = buildMerkleTree([
+                { address: recipient.keypair.publicKey, amount: BigInt(1_000) },
+            ]);
+            const scheduleId = randomId();
+            const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            const executeAt = (await getChainTime(ctx.provider.connection)) + 2;
+
+            await ctx.program.methods
+                .createOneShotSchedule(
+                    scheduleId,
+                    new BN(executeAt),
+                    new BN(1_000),
+                    Array.from(root),
+                    1,
+                    randomId()
+                )
+                .accountsPartial({
+                    authority: employer.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employer])
+                .rpc();
+            await waitForChainTime(ctx.provider.connection, executeAt);
+
+            // Nobody claimed, but the batch timeout (a week in tests) has not passed yet
+            try {
+                await settle(scheduleId, schedulePda).rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("BatchNotSettleable");
+            }
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ active: {} });
+            expect(schedule.reservedAmount.toNumber()).to.equal(1_000);
+        });
+    });
+
+    describe("protocol fee on a vault with nothing available", () => {
+        const FEE_BPS = 100;
+        let treasuryAta: PublicKey;
+        let recipient: { keypair: Keypair; ata: PublicKey };
+        let recipients: Recipient[];
+        let schedule: DueSchedule;
+
+        before(async () => {
+            const treasury = Keypair.generate();
+            treasuryAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                treasury.publicKey
+            );
+            recipient = await newRecipient();
+            recipients = [{ address: recipient.keypair.publicKey, amount: BigInt(1_000) }];
+
+            // Reserve everything the vault has, leaving nothing to pay a fee from
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            schedule = await createDueSchedule(recipients, 1_000, vault.available.toNumber());
+
+            await ctx.program.methods
+                .setProtocolFee(FEE_BPS, treasury.publicKey)
+                .accountsPartial({ governance: ctx.governance.publicKey })
+                .signers([ctx.governance])
+                .rpc();
+        });
+
+        after(async () => {
+            await ctx.program.methods
+                .setProtocolFee(0, PublicKey.default)
+                .accountsPartial({ governance: ctx.governance.publicKey })
+                .signers([ctx.governance])
+                .rpc();
+        });
+
+        it("Should pay the recipient and defer the fee", async () => {
+            await claim(schedule, recipients[0], recipient.ata, 0)
+                .accountsPartial({ treasuryAta })
+                .rpc();
+
+            const balanceOf = async (ata: PublicKey) =>
+                Number((await getAccount(ctx.provider.connection, ata)).amount);
+            expect(await balanceOf(recipient.ata)).to.equal(1_000);
+            expect(await balanceOf(treasuryAta)).to.equal(0);
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.available.toNumber()).to.equal(0);
+            expect(vault.feesOwed.toNumber()).to.equal(10);
+        });
+
+        it("Should pay the owed fee with the next one", async () => {
+            await ctx.program.methods
+                .cancelSchedule()
+                .accountsPartial({
+                    authority: employer.publicKey,
+                    vault: vaultPda,
+                    schedule: schedule.schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            const next = await newRecipient();
+            const nextRecipients = [{ address: next.keypair.publicKey, amount: BigInt(1_000) }];
+            const nextSchedule = await createDueSchedule(nextRecipients, 1_000, 1_000);
+            await claim(nextSchedule, nextRecipients[0], next.ata, 0)
+                .accountsPartial({ treasuryAta })
+                .rpc();
+
+            const treasuryBalance = Number(
+                (await getAccount(ctx.provider.connection, treasuryAta)).amount
+            );
+            expect(treasuryBalance).to.equal(20);
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.feesOwed.toNumber()).to.equal(0);
+        });
+    });
+
+    // The coordinator loads the SDK's checked-in IDL at runtime, so decode and claim
+    // through it the way executor.ts does rather than through the workspace types
+    describe("protocol fee through the SDK's bundled IDL", () => {
+        const FEE_BPS = 250;
+        let sdkProgram: Program;
+        let treasuryAta: PublicKey;
+
+        before(async () => {
+            sdkProgram = new Program(sdkIdl as Idl, ctx.provider);
+            const treasury = Keypair.generate();
+            treasuryAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                treasury.publicKey
+            );
+
+            await ctx.program.methods
+                .setProtocolFee(FEE_BPS, treasury.publicKey)
+                .accountsPartial({ governance: ctx.governance.publicKey })
+                .signers([ctx.governance])
+                .rpc();
+        });
+
+        after(async () => {
+            await ctx.program.methods
+                .setProtocolFee(0, PublicKey.default)
+                .accountsPartial({ governance: ctx.governance.publicKey })
+                .signers([ctx.governance])
+                .rpc();
+        });
+
+        it("Should decode the fee and pay it to the treasury on a claim", async () => {
+            const accounts = sdkProgram.account as any;
+            const veilConfig = await accounts.veilConfig.fetch(ctx.configPda);
+            expect(veilConfig.feeBps).to.equal(FEE_BPS);
+            const resolvedTreasuryAta = await getAssociatedTokenAddress(
+                ctx.allowedMint,
+                veilConfig.treasury as PublicKey
+            );
+            expect(resolvedTreasuryAta.toBase58()).to.equal(treasuryAta.toBase58());
+
+            const recipient = await newRecipient();
+            const recipients: Recipient[] = [
+                { address: recipient.keypair.publicKey, amount: BigInt(2_000) },
+            ];
+            const schedule = await createDueSchedule(recipients, 2_000, 4_000);
+            const onChain = await accounts.scheduleAccount.fetch(schedule.schedulePda);
+            const batch = (onChain.lastExecutedBatch as BN).toNumber() + 1;
+
+            await sdkProgram.methods
+                .claimPayment(
+                    schedule.scheduleId,
+                    recipient.keypair.publicKey,
+                    new BN(2_000),
+                    0,
+                    schedule.proofs[0],
+                    []
+                )
+                .accountsStrict({
+                    erAuthority: ctx.erAuthority.publicKey,
+                    config: ctx.configPda,
+                    vault: vaultPda,
+                    vaultAta: vaultAtaPda,
+                    schedule: schedule.schedulePda,
+                    recipientAta: recipient.ata,
+                    tokenMint: ctx.allowedMint,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    batchRecord: getBatchRecordPda(schedule.schedulePda, batch)[0],
+                    systemProgram: SystemProgram.programId,
+                    receipt: getReceiptPda(schedule.schedulePda, batch, 0)[0],
+                    treasuryAta: resolvedTreasuryAta,
+                    scheduleIndex: getScheduleIndexPda(vaultPda)[0],
+                })
+                .signers([ctx.erAuthority])
+                .rpc();
+
+            const balanceOf = async (ata: PublicKey) =>
+                Number((await getAccount(ctx.provider.connection, ata)).amount);
+            expect(await balanceOf(recipient.ata)).to.equal(2_000);
+            expect(await balanceOf(treasuryAta)).to.equal((2_000 * FEE_BPS) / 10_000);
+        });
+    });
+});
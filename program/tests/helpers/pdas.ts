@@ -32,3 +32,66 @@ export function getSchedulePda(vault: PublicKey, scheduleId: number[]): [PublicK
         PROGRAM_ID
     );
 }
+
+export function getLeafClaimPda(schedule: PublicKey, leafIndex: number): [PublicKey, number] {
+    const leafBytes = Buffer.alloc(2);
+    leafBytes.writeUInt16LE(leafIndex);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("leaf_claim"), schedule.toBuffer(), leafBytes],
+        PROGRAM_ID
+    );
+}
+
+export function getPendingActionPda(vault: PublicKey, nonce: number): [PublicKey, number] {
+    const nonceBytes = Buffer.alloc(8);
+    nonceBytes.writeBigUInt64LE(BigInt(nonce));
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("pending_action"), vault.toBuffer(), nonceBytes],
+        PROGRAM_ID
+    );
+}
+
+export function getOperatorPda(vault: PublicKey, operator: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("operator"), vault.toBuffer(), operator.toBuffer()],
+        PROGRAM_ID
+    );
+}
+
+export function getOrganizationPda(creator: PublicKey, nameHash: number[]): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("organization"), creator.toBuffer(), Buffer.from(nameHash)],
+        PROGRAM_ID
+    );
+}
+
+export function getScheduleIndexPda(vault: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("schedule_index"), vault.toBuffer()],
+        PROGRAM_ID
+    );
+}
+
+export function getBatchRecordPda(schedule: PublicKey, batch: number): [PublicKey, number] {
+    const batchBytes = Buffer.alloc(8);
+    batchBytes.writeBigUInt64LE(BigInt(batch));
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("batch_record"), schedule.toBuffer(), batchBytes],
+        PROGRAM_ID
+    );
+}
+
+export function getReceiptPda(
+    schedule: PublicKey,
+    batch: number,
+    leafIndex: number
+): [PublicKey, number] {
+    const batchBytes = Buffer.alloc(8);
+    batchBytes.writeBigUInt64LE(BigInt(batch));
+    const leafBytes = Buffer.alloc(2);
+    leafBytes.writeUInt16LE(leafIndex);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("receipt"), schedule.toBuffer(), batchBytes, leafBytes],
+        PROGRAM_ID
+    );
+}
@@ -24,13 +24,16 @@ export interface TestContext {
     configPda: PublicKey;
 }
 
+// Shared by every context in a run, so whichever test file initializes the config,
+// the others can still sign as its governance and ER authority
+const governance = Keypair.generate();
+const erAuthority = Keypair.generate();
+
 export async function createTestContext(): Promise<TestContext> {
     const provider = anchor.AnchorProvider.env();
     anchor.setProvider(provider);
     const program = anchor.workspace.veil as Program<Veil>;
 
-    const governance = Keypair.generate();
-    const erAuthority = Keypair.generate();
     const employer = Keypair.generate();
 
     const [configPda] = getConfigPda();
@@ -149,6 +152,17 @@ export async function ensureUnpaused(ctx: TestContext): Promise<void> {
     }
 }
 
+export async function ensureErAuthority(ctx: TestContext): Promise<void> {
+    const config = await ctx.program.account.veilConfig.fetch(ctx.configPda);
+    if (!config.erAuthority.equals(ctx.erAuthority.publicKey)) {
+        await ctx.program.methods
+            .setErAuthority(ctx.erAuthority.publicKey)
+            .accountsPartial({ governance: ctx.governance.publicKey })
+            .signers([ctx.governance])
+            .rpc();
+    }
+}
+
 export async function ensurePaused(ctx: TestContext): Promise<void> {
     const config = await ctx.program.account.veilConfig.fetch(ctx.configPda);
     if (!config.paused) {
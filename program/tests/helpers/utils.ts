@@ -36,3 +36,39 @@ export function sleep(ms: number): Promise<void> {
     return new Promise((resolve) => setTimeout(resolve, ms));
 }
 
+
+export async function getChainTime(connection: Connection): Promise<number> {
+    const slot = await connection.getSlot("confirmed");
+    return (await connection.getBlockTime(slot)) ?? Math.floor(Date.now() / 1000);
+}
+
+export async function waitForChainTime(connection: Connection, timestamp: number): Promise<void> {
+    while ((await getChainTime(connection)) < timestamp) {
+        await sleep(500);
+    }
+}
+
+// Events the program emitted in a confirmed transaction
+export async function getEvents(
+    program: anchor.Program<any>,
+    signature: string
+): Promise<anchor.Event[]> {
+    const connection = program.provider.connection;
+    let tx = null;
+    while (tx === null) {
+        tx = await connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+        });
+        if (tx === null) {
+            await sleep(500);
+        }
+    }
+
+    const parser = new anchor.EventParser(program.programId, program.coder);
+    return Array.from(parser.parseLogs(tx.meta?.logMessages ?? []));
+}
+
+export function findEvent(events: anchor.Event[], name: string): anchor.Event | undefined {
+    return events.find((event) => event.name.toLowerCase() === name.toLowerCase());
+}
@@ -1,6 +1,8 @@
 import { expect } from "chai";
 import { Keypair, PublicKey } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
+import { createAssociatedTokenAccount, getAccount } from "@solana/spl-token";
+import { createHash } from "crypto";
 import {
     createTestContext,
     ensureConfigInitialized,
@@ -10,6 +12,9 @@ import {
     getVaultPda,
     getVaultAtaPda,
     getSchedulePda,
+    getLeafClaimPda,
+    getOperatorPda,
+    getScheduleIndexPda,
     randomId,
     getErrorCode,
 } from "./helpers";
@@ -80,10 +85,14 @@ describe("Schedule Instructions", () => {
                     new BN(perExecutionAmount),
                     merkleRoot,
                     totalRecipients,
-                    erJobId
+                    erJobId,
+                    { skip: {} },
+                    null,
+                    null,
+                    null
                 )
                 .accountsPartial({
-                    employer: employerWithVault.publicKey,
+                    authority: employerWithVault.publicKey,
                     vault: vaultPda,
                 })
                 .signers([employerWithVault])
@@ -97,11 +106,199 @@ describe("Schedule Instructions", () => {
             expect(schedule.perExecutionAmount.toNumber()).to.equal(perExecutionAmount);
             expect(schedule.totalRecipients).to.equal(totalRecipients);
             expect(schedule.paidCount).to.equal(0);
+            expect(schedule.anchorTime.toNumber()).to.equal(schedule.nextExecution.toNumber());
+            expect(schedule.missedPeriodPolicy).to.deep.equal({ skip: {} });
 
             const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
             expect(vault.reserved.toNumber()).to.equal(reservedAmount);
         });
 
+        it("Should record the catch-up missed period policy", async () => {
+            const scheduleId = randomId();
+            const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(20_000),
+                    new BN(2_000),
+                    randomId(),
+                    4,
+                    randomId(),
+                    { catchUp: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.missedPeriodPolicy).to.deep.equal({ catchUp: {} });
+            expect(schedule.anchorTime.toNumber()).to.equal(schedule.nextExecution.toNumber());
+        });
+
+        it("Should create schedule with explicit start and end times", async () => {
+            const scheduleId = randomId();
+            const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            const now = Math.floor(Date.now() / 1000);
+            const startAt = now + 7 * 86400;
+            const endAt = startAt + 90 * 86400;
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(20_000),
+                    new BN(2_000),
+                    randomId(),
+                    4,
+                    randomId(),
+                    { skip: {} },
+                    new BN(startAt),
+                    new BN(endAt),
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.nextExecution.toNumber()).to.equal(startAt);
+            expect(schedule.anchorTime.toNumber()).to.equal(startAt);
+            expect(schedule.endAt.toNumber()).to.equal(endAt);
+        });
+
+        it("Should fail with start time in the past", async () => {
+            const now = Math.floor(Date.now() / 1000);
+
+            try {
+                await ctx.program.methods
+                    .createSchedule(
+                        randomId(),
+                        new BN(86400),
+                        new BN(1000),
+                        new BN(100),
+                        randomId(),
+                        10,
+                        randomId(),
+                        { skip: {} },
+                        new BN(now - 86400),
+                        null,
+                        null
+                    )
+                    .accountsPartial({
+                        authority: employerWithVault.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidStartTime");
+            }
+        });
+
+        it("Should fail with end time before the first execution", async () => {
+            const now = Math.floor(Date.now() / 1000);
+
+            try {
+                await ctx.program.methods
+                    .createSchedule(
+                        randomId(),
+                        new BN(86400),
+                        new BN(1000),
+                        new BN(100),
+                        randomId(),
+                        10,
+                        randomId(),
+                        { skip: {} },
+                        new BN(now + 2 * 86400),
+                        new BN(now + 86400),
+                        null
+                    )
+                    .accountsPartial({
+                        authority: employerWithVault.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidEndTime");
+            }
+        });
+
+        it("Should record a fixed number of executions", async () => {
+            const scheduleId = randomId();
+            const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(12_000),
+                    new BN(1_000),
+                    randomId(),
+                    4,
+                    randomId(),
+                    { skip: {} },
+                    null,
+                    null,
+                    new BN(12)
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.maxExecutions.toNumber()).to.equal(12);
+            expect(schedule.lastExecutedBatch.toNumber()).to.equal(0);
+            expect(schedule.batchPaidAmount.toNumber()).to.equal(0);
+        });
+
+        it("Should fail with zero max_executions", async () => {
+            try {
+                await ctx.program.methods
+                    .createSchedule(
+                        randomId(),
+                        new BN(86400),
+                        new BN(1000),
+                        new BN(100),
+                        randomId(),
+                        10,
+                        randomId(),
+                        { skip: {} },
+                        null,
+                        null,
+                        new BN(0)
+                    )
+                    .accountsPartial({
+                        authority: employerWithVault.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidMaxExecutions");
+            }
+        });
+
         it("Should fail with insufficient funds", async () => {
             const scheduleId = randomId();
             const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
@@ -118,10 +315,14 @@ describe("Schedule Instructions", () => {
                         new BN(10_000),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -147,10 +348,14 @@ describe("Schedule Instructions", () => {
                         new BN(100),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -176,10 +381,14 @@ describe("Schedule Instructions", () => {
                         new BN(100),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -205,10 +414,14 @@ describe("Schedule Instructions", () => {
                         new BN(100),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -234,10 +447,14 @@ describe("Schedule Instructions", () => {
                         new BN(100),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -263,10 +480,14 @@ describe("Schedule Instructions", () => {
                         new BN(0),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -292,10 +513,14 @@ describe("Schedule Instructions", () => {
                         new BN(1001),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -321,10 +546,14 @@ describe("Schedule Instructions", () => {
                         new BN(100),
                         merkleRoot,
                         0,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
                     })
                     .signers([employerWithVault])
@@ -356,10 +585,14 @@ describe("Schedule Instructions", () => {
                         new BN(100),
                         merkleRoot,
                         10,
-                        erJobId
+                        erJobId,
+                        { skip: {} },
+                        null,
+                        null,
+                        null
                     )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                     })
                     .signers([employerWithVault])
                     .rpc();
@@ -373,156 +606,1294 @@ describe("Schedule Instructions", () => {
         });
     });
 
-    describe("cancel_schedule", () => {
-        let scheduleId: number[];
-        let schedulePda: PublicKey;
+    describe("create_one_shot_schedule", () => {
+        it("Should create a one-shot schedule successfully", async () => {
+            const scheduleId = randomId();
+            const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            const executeAt = Math.floor(Date.now() / 1000) + 3 * 86400;
+            const reservedAmount = 15_000;
 
-        before(async () => {
-            scheduleId = randomId();
-            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
-            const merkleRoot = randomId();
-            const erJobId = randomId();
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
 
             await ctx.program.methods
-                .createSchedule(
+                .createOneShotSchedule(
                     scheduleId,
-                    new BN(86400),
-                    new BN(50_000),
-                    new BN(5_000),
-                    merkleRoot,
-                    10,
-                    erJobId
+                    new BN(executeAt),
+                    new BN(reservedAmount),
+                    randomId(),
+                    3,
+                    randomId()
                 )
                 .accountsPartial({
-                    employer: employerWithVault.publicKey,
-                    vault: vaultPda,
-                })
-                .signers([employerWithVault])
-                .rpc();
-        });
-
-        it("Should cancel schedule successfully", async () => {
-            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
-            const scheduleBefore = await ctx.program.account.scheduleAccount.fetch(schedulePda);
-            const reservedAmount = scheduleBefore.reservedAmount.toNumber();
-
-            await ctx.program.methods
-                .cancelSchedule()
-                .accountsPartial({
-                    employer: employerWithVault.publicKey,
+                    authority: employerWithVault.publicKey,
                     vault: vaultPda,
-                    schedule: schedulePda,
                 })
                 .signers([employerWithVault])
                 .rpc();
 
             const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
-            expect(schedule.status).to.deep.equal({ cancelled: {} });
+            expect(schedule.kind).to.deep.equal({ oneShot: {} });
+            expect(schedule.nextExecution.toNumber()).to.equal(executeAt);
+            expect(schedule.intervalSecs.toNumber()).to.equal(0);
+            expect(schedule.perExecutionAmount.toNumber()).to.equal(reservedAmount);
+            expect(schedule.maxExecutions.toNumber()).to.equal(1);
 
             const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
             expect(vaultAfter.reserved.toNumber()).to.equal(
-                vaultBefore.reserved.toNumber() - reservedAmount
-            );
-            expect(vaultAfter.available.toNumber()).to.equal(
-                vaultBefore.available.toNumber() + reservedAmount
+                vaultBefore.reserved.toNumber() + reservedAmount
             );
         });
 
-        it("Should fail if schedule already cancelled", async () => {
+        it("Should fail with execute_at in the past", async () => {
+            const executeAt = Math.floor(Date.now() / 1000) - 86400;
+
             try {
                 await ctx.program.methods
-                    .cancelSchedule()
+                    .createOneShotSchedule(
+                        randomId(),
+                        new BN(executeAt),
+                        new BN(1_000),
+                        randomId(),
+                        3,
+                        randomId()
+                    )
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: employerWithVault.publicKey,
                         vault: vaultPda,
-                        schedule: schedulePda,
                     })
                     .signers([employerWithVault])
                     .rpc();
                 expect.fail("Should have failed");
             } catch (err: any) {
                 const errorCode = getErrorCode(err);
-                expect(errorCode).to.equal("ScheduleAlreadyCancelled");
+                expect(errorCode).to.equal("InvalidStartTime");
             }
         });
     });
 
-    describe("pause_schedule", () => {
+    describe("create_stream_schedule / claim_stream", () => {
+        const ratePerSec = 10;
+        let recipient: Keypair;
+        let startTime: number;
+        let endTime: number;
         let scheduleId: number[];
         let schedulePda: PublicKey;
 
         before(async () => {
+            recipient = Keypair.generate();
+            const now = Math.floor(Date.now() / 1000);
+            startTime = now - 100;
+            endTime = now + 1_000;
+
+            // Single-leaf tree: the root is the leaf hash and the proof is empty
+            const leaf = Buffer.alloc(56);
+            leaf.set(recipient.publicKey.toBuffer(), 0);
+            leaf.writeBigUInt64LE(BigInt(ratePerSec), 32);
+            leaf.writeBigUInt64LE(BigInt(startTime), 40);
+            leaf.writeBigUInt64LE(BigInt(endTime), 48);
+            const merkleRoot = Array.from(createHash("sha256").update(leaf).digest());
+
             scheduleId = randomId();
             [schedulePda] = getSchedulePda(vaultPda, scheduleId);
-            const merkleRoot = randomId();
-            const erJobId = randomId();
 
             await ctx.program.methods
-                .createSchedule(
-                    scheduleId,
-                    new BN(86400),
-                    new BN(50_000),
-                    new BN(5_000),
-                    merkleRoot,
-                    10,
-                    erJobId
-                )
+                .createStreamSchedule(scheduleId, new BN(20_000), merkleRoot, 1, randomId())
                 .accountsPartial({
-                    employer: employerWithVault.publicKey,
+                    authority: employerWithVault.publicKey,
                     vault: vaultPda,
                 })
                 .signers([employerWithVault])
                 .rpc();
         });
 
-        it("Should pause schedule successfully", async () => {
+        it("Should create a stream schedule successfully", async () => {
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.kind).to.deep.equal({ stream: {} });
+            expect(schedule.reservedAmount.toNumber()).to.equal(20_000);
+            expect(schedule.perExecutionAmount.toNumber()).to.equal(0);
+        });
+
+        it("Should let the recipient withdraw accrued pay", async () => {
             await ctx.program.methods
-                .pauseSchedule(true)
+                .fundRentBudget(new BN(10_000_000))
                 .accountsPartial({
                     employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
                 })
                 .signers([employerWithVault])
                 .rpc();
 
-            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
-            expect(schedule.status).to.deep.equal({ paused: {} });
-        });
+            const recipientAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                recipient.publicKey
+            );
+            const [leafClaimPda] = getLeafClaimPda(schedulePda, 0);
 
-        it("Should resume schedule successfully", async () => {
             await ctx.program.methods
-                .pauseSchedule(false)
+                .claimStream(
+                    scheduleId,
+                    recipient.publicKey,
+                    new BN(ratePerSec),
+                    new BN(startTime),
+                    new BN(endTime),
+                    0,
+                    []
+                )
                 .accountsPartial({
-                    employer: employerWithVault.publicKey,
+                    claimer: recipient.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                    leafClaim: leafClaimPda,
+                    recipientAta,
+                    tokenMint: ctx.allowedMint,
                 })
-                .signers([employerWithVault])
+                .signers([recipient])
                 .rpc();
 
+            const balance = Number((await getAccount(ctx.provider.connection, recipientAta)).amount);
+            expect(balance).to.be.greaterThan(0);
+
+            const leafClaim = await ctx.program.account.leafClaim.fetch(leafClaimPda);
+            expect(leafClaim.claimed.toNumber()).to.equal(balance);
+
             const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
-            expect(schedule.status).to.deep.equal({ active: {} });
+            expect(schedule.reservedAmount.toNumber()).to.equal(20_000 - balance);
         });
+    });
+
+    describe("create_vesting_schedule / revoke_vesting / claim_vested", () => {
+        let recipient: Keypair;
+        let grant: {
+            total: BN;
+            cliffTs: BN;
+            startTs: BN;
+            endTs: BN;
+            periodSecs: BN;
+        };
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+        let leafClaimPda: PublicKey;
+
+        before(async () => {
+            recipient = Keypair.generate();
+            const now = Math.floor(Date.now() / 1000);
+            grant = {
+                total: new BN(10_000),
+                cliffTs: new BN(now - 1_000),
+                startTs: new BN(now - 1_000),
+                endTs: new BN(now + 1_000),
+                periodSecs: new BN(100),
+            };
+
+            // Single-leaf tree: the root is the leaf hash and the proof is empty
+            const leaf = Buffer.alloc(72);
+            leaf.set(recipient.publicKey.toBuffer(), 0);
+            [grant.total, grant.cliffTs, grant.startTs, grant.endTs, grant.periodSecs].forEach(
+                (value, i) => leaf.writeBigUInt64LE(BigInt(value.toString()), 32 + i * 8)
+            );
+            const merkleRoot = Array.from(createHash("sha256").update(leaf).digest());
+
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            [leafClaimPda] = getLeafClaimPda(schedulePda, 0);
 
-        it("Should fail if already paused", async () => {
             await ctx.program.methods
-                .pauseSchedule(true)
+                .createVestingSchedule(scheduleId, new BN(10_000), merkleRoot, 1, randomId())
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            await ctx.program.methods
+                .fundRentBudget(new BN(10_000_000))
                 .accountsPartial({
                     employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
                 })
                 .signers([employerWithVault])
                 .rpc();
+        });
 
+        it("Should fail if a non-operator revokes a grant", async () => {
+            const stranger = Keypair.generate();
             try {
                 await ctx.program.methods
-                    .pauseSchedule(true)
+                    .revokeVesting(recipient.publicKey, grant, 0, [])
                     .accountsPartial({
-                        employer: employerWithVault.publicKey,
+                        authority: stranger.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                        leafClaim: leafClaimPda,
                     })
-                    .signers([employerWithVault])
+                    .signers([stranger])
                     .rpc();
                 expect.fail("Should have failed");
             } catch (err: any) {
                 const errorCode = getErrorCode(err);
-                expect(errorCode).to.equal("ScheduleAlreadyPaused");
+                expect(errorCode).to.equal("Unauthorized");
             }
         });
+
+        it("Should revoke the unvested part of a grant", async () => {
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await ctx.program.methods
+                .revokeVesting(recipient.publicKey, grant, 0, [])
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                    leafClaim: leafClaimPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const leafClaim = await ctx.program.account.leafClaim.fetch(leafClaimPda);
+            const vested = leafClaim.vestedCap.toNumber();
+            expect(vested).to.be.greaterThan(0);
+            expect(vested).to.be.lessThan(10_000);
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.reservedAmount.toNumber()).to.equal(vested);
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(
+                vaultBefore.available.toNumber() + 10_000 - vested
+            );
+        });
+
+        it("Should let the recipient claim what vested before revocation", async () => {
+            const recipientAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                recipient.publicKey
+            );
+
+            await ctx.program.methods
+                .claimVested(scheduleId, recipient.publicKey, grant, 0, [])
+                .accountsPartial({
+                    claimer: recipient.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                    leafClaim: leafClaimPda,
+                    recipientAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([recipient])
+                .rpc();
+
+            const leafClaim = await ctx.program.account.leafClaim.fetch(leafClaimPda);
+            const balance = Number((await getAccount(ctx.provider.connection, recipientAta)).amount);
+            expect(balance).to.equal(leafClaim.vestedCap.toNumber());
+            expect(leafClaim.claimed.toNumber()).to.equal(balance);
+        });
+
+        it("Should fail to revoke the same grant twice", async () => {
+            try {
+                await ctx.program.methods
+                    .revokeVesting(recipient.publicKey, grant, 0, [])
+                    .accountsPartial({
+                        authority: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                        leafClaim: leafClaimPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("VestingAlreadyRevoked");
+            }
+        });
+    });
+
+    describe("fund_schedule / defund_schedule", () => {
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+
+        before(async () => {
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(10_000),
+                    new BN(5_000),
+                    randomId(),
+                    5,
+                    randomId(),
+                    { skip: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should top up a running schedule", async () => {
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await ctx.program.methods
+                .fundSchedule(new BN(15_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ active: {} });
+            expect(schedule.reservedAmount.toNumber()).to.equal(25_000);
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(vaultBefore.available.toNumber() - 15_000);
+            expect(vaultAfter.reserved.toNumber()).to.equal(vaultBefore.reserved.toNumber() + 15_000);
+        });
+
+        it("Should defund future cycles", async () => {
+            await ctx.program.methods
+                .defundSchedule(new BN(10_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.reservedAmount.toNumber()).to.equal(15_000);
+        });
+
+        it("Should enable auto-refill on a running schedule", async () => {
+            await ctx.program.methods
+                .setAutoRefill(3)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.autoRefillCycles).to.equal(3);
+            expect(schedule.status).to.deep.equal({ active: {} });
+        });
+
+        it("Should stage a recipient update without moving the cadence", async () => {
+            const before = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            const newRoot = randomId();
+
+            await ctx.program.methods
+                .stageScheduleUpdate(newRoot, 6, new BN(6_000))
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ active: {} });
+            expect(schedule.pendingMerkleRoot).to.deep.equal(newRoot);
+            expect(schedule.pendingTotalRecipients).to.equal(6);
+            expect(schedule.pendingPerExecutionAmount.toNumber()).to.equal(6_000);
+            expect(schedule.merkleRoot).to.deep.equal(before.merkleRoot);
+            expect(schedule.nextExecution.toNumber()).to.equal(before.nextExecution.toNumber());
+        });
+
+        it("Should withhold and release a recipient leaf", async () => {
+            await ctx.program.methods
+                .withholdRecipient(2, true)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            let schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.withheldBitmap[0]).to.equal(1 << 2);
+
+            await ctx.program.methods
+                .withholdRecipient(2, false)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.withheldBitmap[0]).to.equal(0);
+        });
+
+        it("Should fail to withhold a leaf outside the tree", async () => {
+            try {
+                await ctx.program.methods
+                    .withholdRecipient(5, true)
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidLeafIndex");
+            }
+        });
+
+        it("Should set a claim window for missed batches", async () => {
+            await ctx.program.methods
+                .setClaimWindow(new BN(7 * 86400))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.claimWindowSecs.toNumber()).to.equal(7 * 86400);
+        });
+
+        it("Should fail with a claim window over the maximum", async () => {
+            try {
+                await ctx.program.methods
+                    .setClaimWindow(new BN(91 * 86400))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidClaimWindow");
+            }
+        });
+
+        it("Should fund and withdraw the rent budget", async () => {
+            await ctx.program.methods
+                .fundRentBudget(new BN(10_000_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            let schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.rentBudget.toNumber()).to.equal(10_000_000);
+
+            await ctx.program.methods
+                .withdrawRentBudget(new BN(4_000_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.rentBudget.toNumber()).to.equal(6_000_000);
+        });
+
+        it("Should fail to withdraw more than the rent budget", async () => {
+            try {
+                await ctx.program.methods
+                    .withdrawRentBudget(new BN(6_000_001))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InsufficientRentBudget");
+            }
+        });
+
+        it("Should enable receipt mode", async () => {
+            await ctx.program.methods
+                .setReceiptMode(true)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.receiptMode).to.equal(true);
+        });
+
+        it("Should close no receipts without touching the rent budget", async () => {
+            const before = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+
+            await ctx.program.methods
+                .closeReceipts()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.rentBudget.toNumber()).to.equal(before.rentBudget.toNumber());
+        });
+
+        it("Should fail to switch payout mode on a running schedule", async () => {
+            try {
+                await ctx.program.methods
+                    .setPayoutMode({ bps: {} })
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ScheduleNotPaused");
+            }
+        });
+
+        it("Should set a keeper tip", async () => {
+            await ctx.program.methods
+                .setKeeperTip(new BN(5_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.keeperTip.toNumber()).to.equal(5_000);
+        });
+
+        it("Should fail to set a keeper tip above the maximum", async () => {
+            try {
+                await ctx.program.methods
+                    .setKeeperTip(new BN(10_000_001))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidKeeperTip");
+            }
+        });
+
+        it("Should fail to defund the current cycle", async () => {
+            try {
+                await ctx.program.methods
+                    .defundSchedule(new BN(10_001))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ExceedsFutureCycles");
+            }
+        });
+    });
+
+    describe("cancel_schedule", () => {
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+
+        before(async () => {
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            const merkleRoot = randomId();
+            const erJobId = randomId();
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(50_000),
+                    new BN(5_000),
+                    merkleRoot,
+                    10,
+                    erJobId,
+                    { skip: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should cancel schedule successfully", async () => {
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            const scheduleBefore = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            const reservedAmount = scheduleBefore.reservedAmount.toNumber();
+
+            await ctx.program.methods
+                .cancelSchedule()
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ cancelled: {} });
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.reserved.toNumber()).to.equal(
+                vaultBefore.reserved.toNumber() - reservedAmount
+            );
+            expect(vaultAfter.available.toNumber()).to.equal(
+                vaultBefore.available.toNumber() + reservedAmount
+            );
+        });
+
+        it("Should fail if schedule already cancelled", async () => {
+            try {
+                await ctx.program.methods
+                    .cancelSchedule()
+                    .accountsPartial({
+                        authority: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ScheduleAlreadyCancelled");
+            }
+        });
+    });
+
+    describe("schedule index / close_schedule", () => {
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+        let indexPda: PublicKey;
+        let indexSizeBefore: number;
+
+        const indexSize = async () =>
+            (await ctx.provider.connection.getAccountInfo(indexPda))!.data.length;
+
+        before(async () => {
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            [indexPda] = getScheduleIndexPda(vaultPda);
+            indexSizeBefore = await indexSize();
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(20_000),
+                    new BN(2_000),
+                    randomId(),
+                    5,
+                    randomId(),
+                    { skip: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should list a new schedule in the vault's index", async () => {
+            const index = await ctx.program.account.vaultScheduleIndex.fetch(indexPda);
+            const entry = index.entries.find((e) => e.schedule.equals(schedulePda));
+            expect(entry).to.not.be.undefined;
+            expect(entry!.scheduleId).to.deep.equal(scheduleId);
+            expect(entry!.status).to.deep.equal({ active: {} });
+        });
+
+        it("Should grow the index by one entry per schedule", async () => {
+            // schedule_id + schedule pubkey + status
+            expect(await indexSize()).to.equal(indexSizeBefore + 65);
+        });
+
+        it("Should fail to close an active schedule", async () => {
+            try {
+                await ctx.program.methods
+                    .closeSchedule(scheduleId)
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ScheduleNotClosable");
+            }
+        });
+
+        it("Should track the cancelled status in the index", async () => {
+            await ctx.program.methods
+                .cancelSchedule()
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const index = await ctx.program.account.vaultScheduleIndex.fetch(indexPda);
+            const entry = index.entries.find((e) => e.schedule.equals(schedulePda));
+            expect(entry!.status).to.deep.equal({ cancelled: {} });
+        });
+
+        it("Should close a cancelled schedule and drop it from the index", async () => {
+            await ctx.program.methods
+                .closeSchedule(scheduleId)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const closed = await ctx.provider.connection.getAccountInfo(schedulePda);
+            expect(closed).to.be.null;
+
+            const index = await ctx.program.account.vaultScheduleIndex.fetch(indexPda);
+            expect(index.entries.some((e) => e.schedule.equals(schedulePda))).to.be.false;
+            expect(await indexSize()).to.equal(indexSizeBefore);
+        });
+    });
+
+    describe("pause_schedule", () => {
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+
+        before(async () => {
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+            const merkleRoot = randomId();
+            const erJobId = randomId();
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(50_000),
+                    new BN(5_000),
+                    merkleRoot,
+                    10,
+                    erJobId,
+                    { skip: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should pause schedule successfully", async () => {
+            await ctx.program.methods
+                .pauseSchedule(true)
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ paused: {} });
+
+            const index = await ctx.program.account.vaultScheduleIndex.fetch(
+                getScheduleIndexPda(vaultPda)[0]
+            );
+            const entry = index.entries.find((e) => e.schedule.equals(schedulePda));
+            expect(entry!.status).to.deep.equal({ paused: {} });
+        });
+
+        it("Should resume schedule successfully", async () => {
+            await ctx.program.methods
+                .pauseSchedule(false)
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ active: {} });
+
+            const index = await ctx.program.account.vaultScheduleIndex.fetch(
+                getScheduleIndexPda(vaultPda)[0]
+            );
+            const entry = index.entries.find((e) => e.schedule.equals(schedulePda));
+            expect(entry!.status).to.deep.equal({ active: {} });
+        });
+
+        it("Should fail if already paused", async () => {
+            await ctx.program.methods
+                .pauseSchedule(true)
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            try {
+                await ctx.program.methods
+                    .pauseSchedule(true)
+                    .accountsPartial({
+                        authority: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ScheduleAlreadyPaused");
+            }
+        });
+
+        it("Should switch to basis-point leaves while paused", async () => {
+            await ctx.program.methods
+                .setPayoutMode({ bps: {} })
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.payoutMode).to.deep.equal({ bps: {} });
+            expect(schedule.batchDust.toNumber()).to.equal(0);
+        });
+    });
+
+    describe("operators", () => {
+        const PAUSE_SCHEDULE = 1 << 0;
+        const CREATE_SCHEDULE = 1 << 2;
+        let operator: Keypair;
+        let operatorPda: PublicKey;
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+
+        before(async () => {
+            operator = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(operator.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+            [operatorPda] = getOperatorPda(vaultPda, operator.publicKey);
+
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(20_000),
+                    new BN(2_000),
+                    randomId(),
+                    10,
+                    randomId(),
+                    { skip: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should add an operator", async () => {
+            await ctx.program.methods
+                .addOperator(operator.publicKey, PAUSE_SCHEDULE)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    operatorAccount: operatorPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const operatorAccount = await ctx.program.account.operator.fetch(operatorPda);
+            expect(operatorAccount.operator.toBase58()).to.equal(operator.publicKey.toBase58());
+            expect(operatorAccount.permissions).to.equal(PAUSE_SCHEDULE);
+        });
+
+        it("Should let an operator pause a schedule", async () => {
+            await ctx.program.methods
+                .pauseSchedule(true)
+                .accountsPartial({
+                    authority: operator.publicKey,
+                    vault: vaultPda,
+                    schedule: schedulePda,
+                    operator: operatorPda,
+                })
+                .signers([operator])
+                .rpc();
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.status).to.deep.equal({ paused: {} });
+        });
+
+        it("Should fail if the operator lacks the permission", async () => {
+            try {
+                await ctx.program.methods
+                    .cancelSchedule()
+                    .accountsPartial({
+                        authority: operator.publicKey,
+                        vault: vaultPda,
+                        schedule: schedulePda,
+                        operator: operatorPda,
+                    })
+                    .signers([operator])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("Unauthorized");
+            }
+        });
+
+        it("Should fail to add an operator with unknown permission bits", async () => {
+            const other = Keypair.generate();
+            const [otherPda] = getOperatorPda(vaultPda, other.publicKey);
+
+            try {
+                await ctx.program.methods
+                    .addOperator(other.publicKey, CREATE_SCHEDULE | (1 << 7))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                        operatorAccount: otherPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidOperatorPermissions");
+            }
+        });
+
+        it("Should revoke an operator", async () => {
+            await ctx.program.methods
+                .revokeOperator()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    operatorAccount: operatorPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const closed = await ctx.provider.connection.getAccountInfo(operatorPda);
+            expect(closed).to.be.null;
+        });
+    });
+
+    describe("approval-gated schedule instructions", () => {
+        let employer: Keypair;
+        let gatedVaultPda: PublicKey;
+        let scheduleId: number[];
+        let schedulePda: PublicKey;
+
+        async function expectApprovalRequired(request: Promise<string>) {
+            try {
+                await request;
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ApprovalRequired");
+            }
+        }
+
+        before(async () => {
+            employer = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(employer.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+            [gatedVaultPda] = getVaultPda(employer.publicKey, ctx.allowedMint);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employer])
+                .rpc();
+
+            const employerAta = await setupEmployerWithTokens(ctx, employer.publicKey, 100_000);
+            const [vaultAtaPda] = getVaultAtaPda(gatedVaultPda);
+            await ctx.program.methods
+                .deposit(new BN(50_000))
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vaultAta: vaultAtaPda,
+                    employerAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employer])
+                .rpc();
+
+            // Created and paused before approvals are required
+            scheduleId = randomId();
+            [schedulePda] = getSchedulePda(gatedVaultPda, scheduleId);
+            await ctx.program.methods
+                .createSchedule(
+                    scheduleId,
+                    new BN(86400),
+                    new BN(20_000),
+                    new BN(2_000),
+                    randomId(),
+                    10,
+                    randomId(),
+                    { skip: {} },
+                    null,
+                    null,
+                    null
+                )
+                .accountsPartial({
+                    authority: employer.publicKey,
+                    vault: gatedVaultPda,
+                })
+                .signers([employer])
+                .rpc();
+            await ctx.program.methods
+                .pauseSchedule(true)
+                .accountsPartial({
+                    authority: employer.publicKey,
+                    vault: gatedVaultPda,
+                    schedule: schedulePda,
+                })
+                .signers([employer])
+                .rpc();
+
+            await ctx.program.methods
+                .setApprovers([Keypair.generate().publicKey], 1)
+                .accountsPartial({
+                    employer: employer.publicKey,
+                    vault: gatedVaultPda,
+                    pendingAction: null,
+                })
+                .signers([employer])
+                .rpc();
+        });
+
+        it("Should fail to create a one-shot schedule without an approved action", async () => {
+            const id = randomId();
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .createOneShotSchedule(
+                        id,
+                        new BN(Math.floor(Date.now() / 1000) + 3600),
+                        new BN(1_000),
+                        randomId(),
+                        1,
+                        randomId()
+                    )
+                    .accountsPartial({
+                        authority: employer.publicKey,
+                        vault: gatedVaultPda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+        });
+
+        it("Should fail to create a stream schedule without an approved action", async () => {
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .createStreamSchedule(randomId(), new BN(1_000), randomId(), 1, randomId())
+                    .accountsPartial({
+                        authority: employer.publicKey,
+                        vault: gatedVaultPda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+        });
+
+        it("Should fail to create a vesting schedule without an approved action", async () => {
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .createVestingSchedule(randomId(), new BN(1_000), randomId(), 1, randomId())
+                    .accountsPartial({
+                        authority: employer.publicKey,
+                        vault: gatedVaultPda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+        });
+
+        it("Should fail to stage a schedule update without an approved action", async () => {
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .stageScheduleUpdate(randomId(), 5, new BN(1_000))
+                    .accountsPartial({
+                        authority: employer.publicKey,
+                        vault: gatedVaultPda,
+                        schedule: schedulePda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+        });
+
+        it("Should fail to fund or defund a schedule without an approved action", async () => {
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .fundSchedule(new BN(1_000))
+                    .accountsPartial({
+                        employer: employer.publicKey,
+                        vault: gatedVaultPda,
+                        schedule: schedulePda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .defundSchedule(new BN(1_000))
+                    .accountsPartial({
+                        employer: employer.publicKey,
+                        vault: gatedVaultPda,
+                        schedule: schedulePda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+        });
+
+        it("Should fail to withhold a recipient without an approved action", async () => {
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .withholdRecipient(0, true)
+                    .accountsPartial({
+                        employer: employer.publicKey,
+                        vault: gatedVaultPda,
+                        schedule: schedulePda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.withheldBitmap[0]).to.equal(0);
+        });
+
+        it("Should fail to set the payout mode without an approved action", async () => {
+            await expectApprovalRequired(
+                ctx.program.methods
+                    .setPayoutMode({ bps: {} })
+                    .accountsPartial({
+                        employer: employer.publicKey,
+                        vault: gatedVaultPda,
+                        schedule: schedulePda,
+                        pendingAction: null,
+                    })
+                    .signers([employer])
+                    .rpc()
+            );
+
+            const schedule = await ctx.program.account.scheduleAccount.fetch(schedulePda);
+            expect(schedule.payoutMode).to.deep.equal({ amount: {} });
+        });
     });
 });
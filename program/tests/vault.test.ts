@@ -1,6 +1,7 @@
 import { expect } from "chai";
 import { Keypair, PublicKey } from "@solana/web3.js";
-import { BN } from "@coral-xyz/anchor";
+import { BN, Idl, Program } from "@coral-xyz/anchor";
+import { createHash } from "crypto";
 import {
     createTestContext,
     ensureConfigInitialized,
@@ -9,9 +10,12 @@ import {
     TestContext,
     getVaultPda,
     getVaultAtaPda,
+    getPendingActionPda,
+    getOrganizationPda,
     getErrorCode,
 } from "./helpers";
-import { createMint, getAccount } from "@solana/spl-token";
+import { createAssociatedTokenAccount, createMint, getAccount, transfer } from "@solana/spl-token";
+import sdkIdl from "../../sdk/src/idl/idl.json";
 
 describe("Vault Instructions", () => {
     let ctx: TestContext;
@@ -312,6 +316,160 @@ describe("Vault Instructions", () => {
         });
     });
 
+    describe("deposit_for", () => {
+        let employerWithVault: Keypair;
+        let funder: Keypair;
+        let vaultPda: PublicKey;
+        let vaultAtaPda: PublicKey;
+        let funderAta: PublicKey;
+
+        before(async () => {
+            employerWithVault = Keypair.generate();
+            funder = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(employerWithVault.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(funder.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+
+            [vaultPda] = getVaultPda(employerWithVault.publicKey, ctx.allowedMint);
+            [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            funderAta = await setupEmployerWithTokens(ctx, funder.publicKey, 1_000_000);
+        });
+
+        it("Should let a third party deposit into the vault", async () => {
+            await ctx.program.methods
+                .depositFor(new BN(200_000))
+                .accountsPartial({
+                    funder: funder.publicKey,
+                    vault: vaultPda,
+                    vaultAta: vaultAtaPda,
+                    funderAta: funderAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([funder])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.available.toNumber()).to.equal(200_000);
+        });
+
+        it("Should fail if the funder is not on the allowlist", async () => {
+            await ctx.program.methods
+                .updateFunderAllowlist(true, [Keypair.generate().publicKey])
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            try {
+                await ctx.program.methods
+                    .depositFor(new BN(1_000))
+                    .accountsPartial({
+                        funder: funder.publicKey,
+                        vault: vaultPda,
+                        vaultAta: vaultAtaPda,
+                        funderAta: funderAta,
+                        tokenMint: ctx.allowedMint,
+                    })
+                    .signers([funder])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("FunderNotAllowed");
+            }
+        });
+
+        it("Should accept deposits from an allowed funder", async () => {
+            await ctx.program.methods
+                .updateFunderAllowlist(true, [funder.publicKey])
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            await ctx.program.methods
+                .depositFor(new BN(100_000))
+                .accountsPartial({
+                    funder: funder.publicKey,
+                    vault: vaultPda,
+                    vaultAta: vaultAtaPda,
+                    funderAta: funderAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([funder])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.available.toNumber()).to.equal(300_000);
+            expect(vault.allowedFunders.map((f) => f.toBase58())).to.deep.equal([
+                funder.publicKey.toBase58(),
+            ]);
+        });
+
+        it("Should reconcile tokens sent straight to the vault ATA", async () => {
+            const before = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await transfer(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                funderAta,
+                vaultAtaPda,
+                funder,
+                50_000
+            );
+
+            await ctx.program.methods
+                .reconcileVault()
+                .accountsPartial({
+                    caller: funder.publicKey,
+                    vault: vaultPda,
+                    vaultAta: vaultAtaPda,
+                })
+                .signers([funder])
+                .rpc();
+
+            const after = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(after.available.toNumber()).to.equal(before.available.toNumber() + 50_000);
+            expect(after.reserved.toNumber()).to.equal(before.reserved.toNumber());
+        });
+
+        it("Should fail to enable an empty allowlist", async () => {
+            try {
+                await ctx.program.methods
+                    .updateFunderAllowlist(true, [])
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InvalidFunderAllowlist");
+            }
+        });
+    });
+
     describe("withdraw", () => {
         let employerWithVault: Keypair;
         let vaultPda: PublicKey;
@@ -369,7 +527,7 @@ describe("Vault Instructions", () => {
                 .accountsPartial({
                     employer: employerWithVault.publicKey,
                     vaultAta: vaultAtaPda,
-                    employerAta: employerAta,
+                    destinationAta: employerAta,
                     tokenMint: ctx.allowedMint,
                 })
                 .signers([employerWithVault])
@@ -389,6 +547,95 @@ describe("Vault Instructions", () => {
             );
         });
 
+        it("Should withdraw to a third-party token account", async () => {
+            const coldWallet = Keypair.generate();
+            const coldWalletAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                coldWallet.publicKey
+            );
+            const [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await ctx.program.methods
+                .withdraw(new BN(10_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vaultAta: vaultAtaPda,
+                    destinationAta: coldWalletAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const coldWalletAccount = await getAccount(ctx.provider.connection, coldWalletAta);
+            expect(Number(coldWalletAccount.amount)).to.equal(10_000);
+        });
+
+        // VeilClient.withdraw builds the call from the SDK's checked-in IDL, not the workspace types
+        it("Should withdraw to a destination through the SDK's bundled IDL", async () => {
+            const sdkProgram = new Program(sdkIdl as Idl, ctx.provider);
+            const destinationAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                Keypair.generate().publicKey
+            );
+            const [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await sdkProgram.methods
+                .withdraw(new BN(5_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    vaultAta: vaultAtaPda,
+                    destinationAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const destination = await getAccount(ctx.provider.connection, destinationAta);
+            expect(Number(destination.amount)).to.equal(5_000);
+        });
+
+        it("Should fail to withdraw to a destination outside the allowlist", async () => {
+            const [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await ctx.program.methods
+                .updateDestinationAllowlist(true, [employerAta])
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const coldWalletAta = await createAssociatedTokenAccount(
+                ctx.provider.connection,
+                ctx.admin.payer,
+                ctx.allowedMint,
+                Keypair.generate().publicKey
+            );
+
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(1_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: coldWalletAta,
+                        tokenMint: ctx.allowedMint,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("DestinationNotAllowed");
+            }
+        });
+
         it("Should fail if insufficient available funds", async () => {
             const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
             const withdrawAmount = vault.available.toNumber() + 1;
@@ -400,7 +647,7 @@ describe("Vault Instructions", () => {
                     .accountsPartial({
                         employer: employerWithVault.publicKey,
                         vaultAta: vaultAtaPda,
-                        employerAta: employerAta,
+                        destinationAta: employerAta,
                         tokenMint: ctx.allowedMint,
                     })
                     .signers([employerWithVault])
@@ -427,7 +674,7 @@ describe("Vault Instructions", () => {
                     .accountsPartial({
                         employer: unauthorizedUser.publicKey,
                         vaultAta: unauthorizedVaultAta,
-                        employerAta: unauthorizedEmployerAta,
+                        destinationAta: unauthorizedEmployerAta,
                         tokenMint: ctx.allowedMint,
                     })
                     .signers([unauthorizedUser])
@@ -439,4 +686,646 @@ describe("Vault Instructions", () => {
             }
         });
     });
+
+    describe("withdraw_limit", () => {
+        let employerWithVault: Keypair;
+        let vaultPda: PublicKey;
+        let vaultAtaPda: PublicKey;
+        let employerAta: PublicKey;
+
+        before(async () => {
+            employerWithVault = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(employerWithVault.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+
+            [vaultPda] = getVaultPda(employerWithVault.publicKey, ctx.allowedMint);
+            [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            employerAta = await setupEmployerWithTokens(ctx, employerWithVault.publicKey, 1_000_000);
+
+            await ctx.program.methods
+                .deposit(new BN(500_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vaultAta: vaultAtaPda,
+                    employerAta: employerAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should apply a new limit immediately", async () => {
+            await ctx.program.methods
+                .setWithdrawLimit(new BN(100_000), new BN(86400))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.withdrawLimit.toNumber()).to.equal(100_000);
+            expect(vault.withdrawWindowSecs.toNumber()).to.equal(86400);
+            expect(vault.pendingLimitEffectiveAt.toNumber()).to.equal(0);
+        });
+
+        it("Should fail to withdraw past the limit within the window", async () => {
+            await ctx.program.methods
+                .withdraw(new BN(60_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vaultAta: vaultAtaPda,
+                    destinationAta: employerAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(50_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: employerAta,
+                        tokenMint: ctx.allowedMint,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("WithdrawLimitExceeded");
+            }
+        });
+
+        it("Should stage a looser limit instead of applying it", async () => {
+            await ctx.program.methods
+                .setWithdrawLimit(new BN(1_000_000), new BN(86400))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.withdrawLimit.toNumber()).to.equal(100_000);
+            expect(vault.pendingWithdrawLimit.toNumber()).to.equal(1_000_000);
+            expect(vault.pendingLimitEffectiveAt.toNumber()).to.be.greaterThan(0);
+        });
+
+        it("Should drop a staged loosening when the limit is tightened", async () => {
+            await ctx.program.methods
+                .setWithdrawLimit(new BN(80_000), new BN(86400))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.withdrawLimit.toNumber()).to.equal(80_000);
+            expect(vault.pendingLimitEffectiveAt.toNumber()).to.equal(0);
+        });
+    });
+
+    describe("approvals", () => {
+        let employerWithVault: Keypair;
+        let approverA: Keypair;
+        let approverB: Keypair;
+        let vaultPda: PublicKey;
+        let vaultAtaPda: PublicKey;
+        let employerAta: PublicKey;
+        let pendingActionPda: PublicKey;
+
+        before(async () => {
+            employerWithVault = Keypair.generate();
+            approverA = Keypair.generate();
+            approverB = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(employerWithVault.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+
+            [vaultPda] = getVaultPda(employerWithVault.publicKey, ctx.allowedMint);
+            [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            employerAta = await setupEmployerWithTokens(ctx, employerWithVault.publicKey, 1_000_000);
+
+            await ctx.program.methods
+                .deposit(new BN(500_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vaultAta: vaultAtaPda,
+                    employerAta: employerAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            await ctx.program.methods
+                .setApprovers([approverA.publicKey, approverB.publicKey], 2)
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    pendingAction: null,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should fail to withdraw without an approved action", async () => {
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(10_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: employerAta,
+                        tokenMint: ctx.allowedMint,
+                        pendingAction: null,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ApprovalRequired");
+            }
+        });
+
+        it("Should propose a withdrawal", async () => {
+            [pendingActionPda] = getPendingActionPda(vaultPda, 0);
+
+            await ctx.program.methods
+                .proposeAction({ withdraw: { amount: new BN(10_000), destination: employerAta } })
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                    pendingAction: pendingActionPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const pendingAction = await ctx.program.account.pendingAction.fetch(pendingActionPda);
+            expect(pendingAction.nonce.toNumber()).to.equal(0);
+            expect(pendingAction.approvals.length).to.equal(0);
+        });
+
+        it("Should fail to execute below the threshold", async () => {
+            await ctx.program.methods
+                .approveAction()
+                .accountsPartial({
+                    approver: approverA.publicKey,
+                    vault: vaultPda,
+                    pendingAction: pendingActionPda,
+                })
+                .signers([approverA])
+                .rpc();
+
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(10_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: employerAta,
+                        tokenMint: ctx.allowedMint,
+                        pendingAction: pendingActionPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("NotEnoughApprovals");
+            }
+        });
+
+        it("Should fail if a non-approver approves", async () => {
+            try {
+                await ctx.program.methods
+                    .approveAction()
+                    .accountsPartial({
+                        approver: unauthorizedUser.publicKey,
+                        vault: vaultPda,
+                        pendingAction: pendingActionPda,
+                    })
+                    .signers([unauthorizedUser])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("NotAnApprover");
+            }
+        });
+
+        it("Should fail to execute a different action", async () => {
+            await ctx.program.methods
+                .approveAction()
+                .accountsPartial({
+                    approver: approverB.publicKey,
+                    vault: vaultPda,
+                    pendingAction: pendingActionPda,
+                })
+                .signers([approverB])
+                .rpc();
+
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(20_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: employerAta,
+                        tokenMint: ctx.allowedMint,
+                        pendingAction: pendingActionPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("ActionMismatch");
+            }
+        });
+
+        it("Should execute the approved withdrawal", async () => {
+            const vaultBefore = await ctx.program.account.vaultAccount.fetch(vaultPda);
+
+            await ctx.program.methods
+                .withdraw(new BN(10_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vaultAta: vaultAtaPda,
+                    destinationAta: employerAta,
+                    tokenMint: ctx.allowedMint,
+                    pendingAction: pendingActionPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const vaultAfter = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vaultAfter.available.toNumber()).to.equal(vaultBefore.available.toNumber() - 10_000);
+
+            const closed = await ctx.provider.connection.getAccountInfo(pendingActionPda);
+            expect(closed).to.be.null;
+        });
+    });
+
+    describe("vault ownership transfer", () => {
+        let oldEmployer: Keypair;
+        let newEmployer: Keypair;
+        let vaultPda: PublicKey;
+
+        before(async () => {
+            oldEmployer = Keypair.generate();
+            newEmployer = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(oldEmployer.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+
+            [vaultPda] = getVaultPda(oldEmployer.publicKey, ctx.allowedMint);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: oldEmployer.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([oldEmployer])
+                .rpc();
+        });
+
+        it("Should nominate a new employer", async () => {
+            await ctx.program.methods
+                .transferVaultOwnership(newEmployer.publicKey)
+                .accountsPartial({
+                    employer: oldEmployer.publicKey,
+                    vault: vaultPda,
+                    pendingAction: null,
+                })
+                .signers([oldEmployer])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.pendingEmployer.toBase58()).to.equal(newEmployer.publicKey.toBase58());
+            expect(vault.employer.toBase58()).to.equal(oldEmployer.publicKey.toBase58());
+        });
+
+        it("Should fail if someone else accepts", async () => {
+            try {
+                await ctx.program.methods
+                    .acceptVaultOwnership()
+                    .accountsPartial({
+                        newEmployer: unauthorizedUser.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([unauthorizedUser])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("NoPendingOwnershipTransfer");
+            }
+        });
+
+        it("Should hand the vault to the new employer and keep its address", async () => {
+            await ctx.program.methods
+                .acceptVaultOwnership()
+                .accountsPartial({
+                    newEmployer: newEmployer.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([newEmployer])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.employer.toBase58()).to.equal(newEmployer.publicKey.toBase58());
+            expect(vault.seedOwner.toBase58()).to.equal(oldEmployer.publicKey.toBase58());
+            expect(vault.pendingEmployer).to.be.null;
+        });
+
+        it("Should fail if the previous employer acts on the vault", async () => {
+            try {
+                await ctx.program.methods
+                    .setWithdrawLimit(new BN(1_000), new BN(3600))
+                    .accountsPartial({
+                        employer: oldEmployer.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([oldEmployer])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("Unauthorized");
+            }
+        });
+    });
+
+    describe("organization", () => {
+        let employerWithVault: Keypair;
+        let orgAdmin: Keypair;
+        let vaultPda: PublicKey;
+        let vaultAtaPda: PublicKey;
+        let employerAta: PublicKey;
+        let organizationPda: PublicKey;
+
+        before(async () => {
+            employerWithVault = Keypair.generate();
+            orgAdmin = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(employerWithVault.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(orgAdmin.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+
+            [vaultPda] = getVaultPda(employerWithVault.publicKey, ctx.allowedMint);
+            [vaultAtaPda] = getVaultAtaPda(vaultPda);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            employerAta = await setupEmployerWithTokens(ctx, employerWithVault.publicKey, 1_000_000);
+
+            await ctx.program.methods
+                .deposit(new BN(300_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vaultAta: vaultAtaPda,
+                    employerAta: employerAta,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should create an organization", async () => {
+            const nameHash = Array.from(createHash("sha256").update("Acme Corp").digest());
+            [organizationPda] = getOrganizationPda(orgAdmin.publicKey, nameHash);
+
+            await ctx.program.methods
+                .createOrganization(nameHash, [orgAdmin.publicKey])
+                .accountsPartial({
+                    creator: orgAdmin.publicKey,
+                    organization: organizationPda,
+                })
+                .signers([orgAdmin])
+                .rpc();
+
+            const organization = await ctx.program.account.organization.fetch(organizationPda);
+            expect(organization.admins.map((a) => a.toBase58())).to.deep.equal([
+                orgAdmin.publicKey.toBase58(),
+            ]);
+            expect(organization.vaults.length).to.equal(0);
+        });
+
+        it("Should add a vault to the organization", async () => {
+            await ctx.program.methods
+                .joinOrganization()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    admin: orgAdmin.publicKey,
+                    vault: vaultPda,
+                    organization: organizationPda,
+                })
+                .signers([employerWithVault, orgAdmin])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.organization.toBase58()).to.equal(organizationPda.toBase58());
+        });
+
+        it("Should refresh organization totals", async () => {
+            await ctx.program.methods
+                .refreshOrganizationTotals()
+                .accountsPartial({
+                    caller: orgAdmin.publicKey,
+                    organization: organizationPda,
+                })
+                .remainingAccounts([{ pubkey: vaultPda, isSigner: false, isWritable: false }])
+                .signers([orgAdmin])
+                .rpc();
+
+            const organization = await ctx.program.account.organization.fetch(organizationPda);
+            expect(organization.totalAvailable.toNumber()).to.equal(300_000);
+            expect(organization.totalReserved.toNumber()).to.equal(0);
+        });
+
+        it("Should apply the organization's default withdraw limit", async () => {
+            await ctx.program.methods
+                .setOrganizationPolicy(new BN(50_000), new BN(86400))
+                .accountsPartial({
+                    admin: orgAdmin.publicKey,
+                    organization: organizationPda,
+                })
+                .signers([orgAdmin])
+                .rpc();
+
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(60_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: employerAta,
+                        tokenMint: ctx.allowedMint,
+                        organization: organizationPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("WithdrawLimitExceeded");
+            }
+        });
+
+        it("Should fail to withdraw without the organization account", async () => {
+            try {
+                await ctx.program.methods
+                    .withdraw(new BN(1_000))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vaultAta: vaultAtaPda,
+                        destinationAta: employerAta,
+                        tokenMint: ctx.allowedMint,
+                        organization: null,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("OrganizationMismatch");
+            }
+        });
+
+        it("Should fail if a non-admin changes the policy", async () => {
+            try {
+                await ctx.program.methods
+                    .setOrganizationPolicy(new BN(0), new BN(0))
+                    .accountsPartial({
+                        admin: employerWithVault.publicKey,
+                        organization: organizationPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("NotAnOrgAdmin");
+            }
+        });
+    });
+
+    describe("tip_budget", () => {
+        let employerWithVault: Keypair;
+        let vaultPda: PublicKey;
+
+        before(async () => {
+            employerWithVault = Keypair.generate();
+            await ctx.provider.connection.confirmTransaction(
+                await ctx.provider.connection.requestAirdrop(employerWithVault.publicKey, 2 * 1e9),
+                "confirmed"
+            );
+
+            [vaultPda] = getVaultPda(employerWithVault.publicKey, ctx.allowedMint);
+
+            await ctx.program.methods
+                .initVault()
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    tokenMint: ctx.allowedMint,
+                })
+                .signers([employerWithVault])
+                .rpc();
+        });
+
+        it("Should fund the tip budget", async () => {
+            const lamportsBefore = await ctx.provider.connection.getBalance(vaultPda);
+
+            await ctx.program.methods
+                .fundTipBudget(new BN(1_000_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.tipBudget.toNumber()).to.equal(1_000_000);
+            expect(vault.available.toNumber()).to.equal(0);
+
+            const lamportsAfter = await ctx.provider.connection.getBalance(vaultPda);
+            expect(lamportsAfter).to.equal(lamportsBefore + 1_000_000);
+        });
+
+        it("Should withdraw from the tip budget", async () => {
+            await ctx.program.methods
+                .withdrawTipBudget(new BN(400_000))
+                .accountsPartial({
+                    employer: employerWithVault.publicKey,
+                    vault: vaultPda,
+                })
+                .signers([employerWithVault])
+                .rpc();
+
+            const vault = await ctx.program.account.vaultAccount.fetch(vaultPda);
+            expect(vault.tipBudget.toNumber()).to.equal(600_000);
+        });
+
+        it("Should fail to withdraw more than the tip budget", async () => {
+            try {
+                await ctx.program.methods
+                    .withdrawTipBudget(new BN(600_001))
+                    .accountsPartial({
+                        employer: employerWithVault.publicKey,
+                        vault: vaultPda,
+                    })
+                    .signers([employerWithVault])
+                    .rpc();
+                expect.fail("Should have failed");
+            } catch (err: any) {
+                const errorCode = getErrorCode(err);
+                expect(errorCode).to.equal("InsufficientTipBudget");
+            }
+        });
+    });
 });
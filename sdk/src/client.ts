@@ -1,4 +1,4 @@
-import { Connection, PublicKey } from "@solana/web3.js";
+import { Connection, PublicKey, SystemProgram } from "@solana/web3.js";
 import { AnchorProvider, Program, Wallet, BN, Idl } from "@coral-xyz/anchor";
 import { getAssociatedTokenAddress } from "@solana/spl-token";
 import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
@@ -7,13 +7,18 @@ import {
     getVaultPda,
     getVaultAtaPda,
     getSchedulePda,
+    getScheduleIndexPda,
+    getBatchRecordPda,
+    getReceiptPda,
 } from "./pda";
-import { buildMerkleTree, Recipient } from "./merkle";
+import { buildMerkleTree, PaymentSplit, Recipient } from "./merkle";
 import {
     VaultAccount,
     ScheduleAccount,
+    ScheduleIndexEntry,
     VeilConfig,
     ScheduleStatus,
+    MissedPeriodPolicy,
     CreateScheduleParams,
     UpdateScheduleParams,
 } from "./types";
@@ -59,8 +64,8 @@ export class VeilClient {
             .rpc();
     }
 
-    async deposit(amount: BN, tokenMint: PublicKey): Promise<string> {
-        const [vaultPda] = getVaultPda(this.wallet.publicKey, tokenMint);
+    async deposit(amount: BN, tokenMint: PublicKey, vault?: PublicKey): Promise<string> {
+        const vaultPda = this.resolveVault(tokenMint, vault);
         const [vaultAtaPda] = getVaultAtaPda(vaultPda);
         const employerAta = await getAssociatedTokenAddress(
             tokenMint,
@@ -79,13 +84,17 @@ export class VeilClient {
             .rpc();
     }
 
-    async withdraw(amount: BN, tokenMint: PublicKey): Promise<string> {
-        const [vaultPda] = getVaultPda(this.wallet.publicKey, tokenMint);
+    async withdraw(
+        amount: BN,
+        tokenMint: PublicKey,
+        destinationAta?: PublicKey,
+        vault?: PublicKey
+    ): Promise<string> {
+        const vaultPda = this.resolveVault(tokenMint, vault);
         const [vaultAtaPda] = getVaultAtaPda(vaultPda);
-        const employerAta = await getAssociatedTokenAddress(
-            tokenMint,
-            this.wallet.publicKey
-        );
+        const destination =
+            destinationAta ??
+            (await getAssociatedTokenAddress(tokenMint, this.wallet.publicKey));
 
         return await this.program.methods
             .withdraw(amount)
@@ -93,7 +102,7 @@ export class VeilClient {
                 employer: this.wallet.publicKey,
                 vault: vaultPda,
                 vaultAta: vaultAtaPda,
-                employerAta,
+                destinationAta: destination,
                 tokenMint,
             })
             .rpc();
@@ -104,9 +113,14 @@ export class VeilClient {
         employer?: PublicKey
     ): Promise<VaultAccount | null> {
         const [vaultPda] = getVaultPda(employer || this.wallet.publicKey, tokenMint);
+        return await this.getVaultByAddress(vaultPda);
+    }
+
+    // Looks a vault up by address, e.g. one taken over through transfer_vault_ownership
+    async getVaultByAddress(vault: PublicKey): Promise<VaultAccount | null> {
         try {
             const accounts = this.program.account as any;
-            return await accounts.vaultAccount.fetch(vaultPda);
+            return await accounts.vaultAccount.fetch(vault);
         } catch {
             return null;
         }
@@ -114,7 +128,7 @@ export class VeilClient {
 
     async createSchedule(params: CreateScheduleParams): Promise<string> {
         assertValidCreateScheduleParams(params);
-        const [vaultPda] = getVaultPda(this.wallet.publicKey, params.tokenMint);
+        const vaultPda = this.resolveVault(params.tokenMint, params.vault);
 
         return await this.program.methods
             .createSchedule(
@@ -124,10 +138,14 @@ export class VeilClient {
                 params.perExecutionAmount,
                 params.merkleRoot,
                 params.totalRecipients,
-                params.erJobId
+                params.erJobId,
+                toMissedPeriodPolicyArg(params.missedPeriodPolicy ?? MissedPeriodPolicy.Skip),
+                toOptionalBn(params.startAt),
+                toOptionalBn(params.endAt),
+                toOptionalBn(params.maxExecutions)
             )
             .accountsPartial({
-                employer: this.wallet.publicKey,
+                authority: this.wallet.publicKey,
                 vault: vaultPda,
             })
             .rpc();
@@ -135,15 +153,20 @@ export class VeilClient {
 
     async createScheduleFromRecipients(opts: {
         tokenMint: PublicKey;
+        vault?: PublicKey;
         recipients: Recipient[];
         intervalSecs: number;
         reservedAmount: BN;
         perExecutionAmount: BN;
+        missedPeriodPolicy?: MissedPeriodPolicy;
+        startAt?: number;
+        endAt?: number;
+        maxExecutions?: number;
     }): Promise<{ signature: string; scheduleId: number[]; merkleRoot: number[] }> {
         const scheduleId = generateScheduleId();
         const erJobId = generateScheduleId();
         const { root } = buildMerkleTree(opts.recipients);
-        const [vaultPda] = getVaultPda(this.wallet.publicKey, opts.tokenMint);
+        const vaultPda = this.resolveVault(opts.tokenMint, opts.vault);
         const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
         assertRecipientsMatchPerExecutionAmount(opts.recipients, opts.perExecutionAmount);
 
@@ -152,6 +175,7 @@ export class VeilClient {
         try {
             signature = await this.createSchedule({
                 tokenMint: opts.tokenMint,
+                vault: vaultPda,
                 scheduleId,
                 intervalSecs: opts.intervalSecs,
                 reservedAmount: opts.reservedAmount,
@@ -159,6 +183,10 @@ export class VeilClient {
                 merkleRoot: Array.from(root),
                 totalRecipients: opts.recipients.length,
                 erJobId,
+                missedPeriodPolicy: opts.missedPeriodPolicy,
+                startAt: opts.startAt,
+                endAt: opts.endAt,
+                maxExecutions: opts.maxExecutions,
             });
         } catch (error) {
             if (error instanceof Error && error.message.includes("already been processed")) {
@@ -192,10 +220,18 @@ export class VeilClient {
                     params.reservedAmount,
                     params.perExecutionAmount,
                     params.merkleRoot,
-                    params.totalRecipients
+                    params.totalRecipients,
+                    toMissedPeriodPolicyArg(
+                        params.missedPeriodPolicy ?? schedule.missedPeriodPolicy
+                    ),
+                    toOptionalBn(params.startAt),
+                    params.endAt === undefined ? schedule.endAt : toOptionalBn(params.endAt),
+                    params.maxExecutions === undefined
+                        ? schedule.maxExecutions
+                        : toOptionalBn(params.maxExecutions)
                 )
                 .accountsPartial({
-                    employer: this.wallet.publicKey,
+                    authority: this.wallet.publicKey,
                     vault: schedule.vault,
                     schedule: schedulePda,
                 })
@@ -218,6 +254,10 @@ export class VeilClient {
         recipients: Recipient[];
         intervalSecs: number;
         reservedAmount: BN;
+        missedPeriodPolicy?: MissedPeriodPolicy;
+        startAt?: number;
+        endAt?: number | null;
+        maxExecutions?: number | null;
     }): Promise<{ signature: string; merkleRoot: number[] }> {
         const { root } = buildMerkleTree(opts.recipients);
         const perExecutionAmount = sumRecipientAmounts(opts.recipients);
@@ -229,6 +269,10 @@ export class VeilClient {
             perExecutionAmount,
             merkleRoot: Array.from(root),
             totalRecipients: opts.recipients.length,
+            missedPeriodPolicy: opts.missedPeriodPolicy,
+            startAt: opts.startAt,
+            endAt: opts.endAt,
+            maxExecutions: opts.maxExecutions,
         });
 
         return {
@@ -237,6 +281,12 @@ export class VeilClient {
         };
     }
 
+    // Vault PDAs are seeded by the employer that created them and keep that address after
+    // transfer_vault_ownership, so a vault taken over from someone else has to be passed in
+    private resolveVault(tokenMint: PublicKey, vault?: PublicKey): PublicKey {
+        return vault ?? getVaultPda(this.wallet.publicKey, tokenMint)[0];
+    }
+
     private async waitForSchedule(schedulePda: PublicKey) {
         for (let attempt = 0; attempt < 5; attempt += 1) {
             const schedule = await this.getSchedule(schedulePda);
@@ -266,11 +316,17 @@ export class VeilClient {
     }
 
     async pauseSchedule(schedulePda: PublicKey, pause: boolean): Promise<string> {
+        const schedule = await this.getSchedule(schedulePda);
+        if (!schedule) {
+            throw new Error("schedule not found");
+        }
+
         try {
             return await this.program.methods
                 .pauseSchedule(pause)
                 .accountsPartial({
-                    employer: this.wallet.publicKey,
+                    authority: this.wallet.publicKey,
+                    vault: schedule.vault,
                     schedule: schedulePda,
                 })
                 .rpc();
@@ -298,7 +354,7 @@ export class VeilClient {
             return await this.program.methods
                 .cancelSchedule()
                 .accountsPartial({
-                    employer: this.wallet.publicKey,
+                    authority: this.wallet.publicKey,
                     vault: schedule.vault,
                     schedule: schedulePda,
                 })
@@ -323,12 +379,43 @@ export class VeilClient {
             return {
                 ...acc,
                 status: parseScheduleStatus(acc.status),
+                missedPeriodPolicy: parseMissedPeriodPolicy(acc.missedPeriodPolicy),
             };
         } catch {
             return null;
         }
     }
 
+    // A schedule that completed inside the rollup shows its old status here until
+    // syncScheduleIndex runs for it
+    async getVaultSchedules(vault: PublicKey): Promise<ScheduleIndexEntry[]> {
+        const [indexPda] = getScheduleIndexPda(vault);
+        const accounts = this.program.account as any;
+        const index = await accounts.vaultScheduleIndex.fetch(indexPda);
+        return index.entries.map((entry: any) => ({
+            scheduleId: entry.scheduleId,
+            schedule: entry.schedule,
+            status: parseScheduleStatus(entry.status),
+        }));
+    }
+
+    async syncScheduleIndex(schedulePda: PublicKey): Promise<string> {
+        const schedule = await this.getSchedule(schedulePda);
+        if (!schedule) {
+            throw new Error("schedule not found");
+        }
+        const [indexPda] = getScheduleIndexPda(schedule.vault);
+
+        return await this.program.methods
+            .syncScheduleIndex()
+            .accountsPartial({
+                caller: this.wallet.publicKey,
+                scheduleIndex: indexPda,
+                schedule: schedulePda,
+            })
+            .rpc();
+    }
+
     async getConfig(): Promise<VeilConfig | null> {
         const [configPda] = getConfigPda();
         try {
@@ -378,19 +465,28 @@ export class VeilClient {
 
     async claimPayment(
         erAuthority: Wallet,
-        vaultEmployer: PublicKey,
+        vault: PublicKey,
         scheduleId: number[],
         recipient: PublicKey,
         amount: BN,
         leafIndex: number,
         proof: number[][],
-        tokenMint: PublicKey
+        tokenMint: PublicKey,
+        splits: PaymentSplit[] = []
     ): Promise<string> {
         const [configPda] = getConfigPda();
-        const [vaultPda] = getVaultPda(vaultEmployer, tokenMint);
-        const [vaultAtaPda] = getVaultAtaPda(vaultPda);
-        const [schedulePda] = getSchedulePda(vaultPda, scheduleId);
+        const [vaultAtaPda] = getVaultAtaPda(vault);
+        const [schedulePda] = getSchedulePda(vault, scheduleId);
         const recipientAta = await getAssociatedTokenAddress(tokenMint, recipient);
+        // The claim belongs to the batch after the last executed one
+        const accounts = this.program.account as any;
+        const schedule = await accounts.scheduleAccount.fetch(schedulePda);
+        const batch = (schedule.lastExecutedBatch as BN).addn(1);
+        const veilConfig = await accounts.veilConfig.fetch(configPda);
+        const treasuryAta =
+            veilConfig.feeBps > 0
+                ? await getAssociatedTokenAddress(tokenMint, veilConfig.treasury as PublicKey)
+                : null;
 
         return await this.program.methods
             .claimPayment(
@@ -398,18 +494,34 @@ export class VeilClient {
                 recipient,
                 amount,
                 leafIndex,
-                proof
+                proof,
+                splits.map((split) => ({
+                    destination: split.destination,
+                    amount: new BN(split.amount.toString()),
+                }))
             )
             .accountsStrict({
                 erAuthority: erAuthority.publicKey,
                 config: configPda,
-                vault: vaultPda,
+                vault,
                 vaultAta: vaultAtaPda,
                 schedule: schedulePda,
                 recipientAta,
                 tokenMint,
                 tokenProgram: TOKEN_PROGRAM_ID,
+                batchRecord: getBatchRecordPda(schedulePda, batch)[0],
+                systemProgram: SystemProgram.programId,
+                receipt: getReceiptPda(schedulePda, batch, leafIndex)[0],
+                treasuryAta,
+                scheduleIndex: getScheduleIndexPda(vault)[0],
             })
+            .remainingAccounts(
+                splits.map((split) => ({
+                    pubkey: split.destination,
+                    isSigner: false,
+                    isWritable: true,
+                }))
+            )
             .signers([erAuthority.payer])
             .rpc();
     }
@@ -436,5 +548,19 @@ async function sleep(ms: number) {
 function parseScheduleStatus(status: any): ScheduleStatus {
     if ("active" in status) return ScheduleStatus.Active;
     if ("paused" in status) return ScheduleStatus.Paused;
+    if ("completed" in status) return ScheduleStatus.Completed;
     return ScheduleStatus.Cancelled;
 }
+
+function toOptionalBn(value?: number | null): BN | null {
+    return value === undefined || value === null ? null : new BN(value);
+}
+
+function parseMissedPeriodPolicy(policy: any): MissedPeriodPolicy {
+    if ("catchUp" in policy) return MissedPeriodPolicy.CatchUp;
+    return MissedPeriodPolicy.Skip;
+}
+
+function toMissedPeriodPolicyArg(policy: MissedPeriodPolicy) {
+    return policy === MissedPeriodPolicy.CatchUp ? { catchUp: {} } : { skip: {} };
+}
@@ -1,9 +1,15 @@
 import { PublicKey } from "@solana/web3.js";
 import { createHash } from "crypto";
 
+export interface PaymentSplit {
+    destination: PublicKey;
+    amount: bigint;
+}
+
 export interface Recipient {
     address: PublicKey;
     amount: bigint;
+    splits?: PaymentSplit[];
 }
 
 export interface MerkleProof {
@@ -11,10 +17,51 @@ export interface MerkleProof {
     proof: Buffer[];
 }
 
-export function hashLeaf(recipient: PublicKey, amount: bigint): Buffer {
-    const data = Buffer.alloc(40);
+export function hashLeaf(
+    recipient: PublicKey,
+    amount: bigint,
+    splits: PaymentSplit[] = []
+): Buffer {
+    const data = Buffer.alloc(40 + splits.length * 40);
     data.set(recipient.toBuffer(), 0);
     data.writeBigUInt64LE(amount, 32);
+    splits.forEach((split, i) => {
+        data.set(split.destination.toBuffer(), 40 + i * 40);
+        data.writeBigUInt64LE(split.amount, 72 + i * 40);
+    });
+    return createHash("sha256").update(data).digest();
+}
+
+export function hashStreamLeaf(
+    recipient: PublicKey,
+    ratePerSec: bigint,
+    startTime: bigint,
+    endTime: bigint
+): Buffer {
+    const data = Buffer.alloc(56);
+    data.set(recipient.toBuffer(), 0);
+    data.writeBigUInt64LE(ratePerSec, 32);
+    data.writeBigUInt64LE(startTime, 40);
+    data.writeBigUInt64LE(endTime, 48);
+    return createHash("sha256").update(data).digest();
+}
+
+export interface VestingGrant {
+    total: bigint;
+    cliffTs: bigint;
+    startTs: bigint;
+    endTs: bigint;
+    periodSecs: bigint;
+}
+
+export function hashVestingLeaf(recipient: PublicKey, grant: VestingGrant): Buffer {
+    const data = Buffer.alloc(72);
+    data.set(recipient.toBuffer(), 0);
+    data.writeBigUInt64LE(grant.total, 32);
+    data.writeBigUInt64LE(grant.cliffTs, 40);
+    data.writeBigUInt64LE(grant.startTs, 48);
+    data.writeBigUInt64LE(grant.endTs, 56);
+    data.writeBigUInt64LE(grant.periodSecs, 64);
     return createHash("sha256").update(data).digest();
 }
 
@@ -32,7 +79,7 @@ export function buildMerkleTree(recipients: Recipient[]): {
     }
 
     // Hash all leaves
-    let leaves = recipients.map((r) => hashLeaf(r.address, r.amount));
+    let leaves = recipients.map((r) => hashLeaf(r.address, r.amount, r.splits));
 
     // Pad to power of 2
     const targetLen = nextPowerOf2(leaves.length);
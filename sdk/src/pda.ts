@@ -1,4 +1,5 @@
 import { PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
 
 export const PROGRAM_ID = new PublicKey(
     "6cibjMX1UwnqSxRkiSBp89NV5Z8Ws3M9i5kizxm8ZnTS"
@@ -47,6 +48,38 @@ export function getSchedulePda(
     );
 }
 
+export function getScheduleIndexPda(vault: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("schedule_index"), vault.toBuffer()],
+        PROGRAM_ID
+    );
+}
+
+export function getBatchRecordPda(schedule: PublicKey, batch: BN): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("batch_record"), schedule.toBuffer(), batch.toArrayLike(Buffer, "le", 8)],
+        PROGRAM_ID
+    );
+}
+
+export function getReceiptPda(
+    schedule: PublicKey,
+    batch: BN,
+    leafIndex: number
+): [PublicKey, number] {
+    const leafBytes = Buffer.alloc(2);
+    leafBytes.writeUInt16LE(leafIndex);
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from("receipt"),
+            schedule.toBuffer(),
+            batch.toArrayLike(Buffer, "le", 8),
+            leafBytes,
+        ],
+        PROGRAM_ID
+    );
+}
+
 export function getBufferPda(account: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
         [Buffer.from("buffer"), account.toBuffer()],
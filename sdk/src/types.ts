@@ -5,6 +5,12 @@ export enum ScheduleStatus {
     Active = "Active",
     Paused = "Paused",
     Cancelled = "Cancelled",
+    Completed = "Completed",
+}
+
+export enum MissedPeriodPolicy {
+    Skip = "Skip",
+    CatchUp = "CatchUp",
 }
 
 export interface VaultAccount {
@@ -22,6 +28,10 @@ export interface ScheduleAccount {
     status: ScheduleStatus;
     intervalSecs: BN;
     nextExecution: BN;
+    anchorTime: BN;
+    missedPeriodPolicy: MissedPeriodPolicy;
+    endAt: BN | null;
+    maxExecutions: BN | null;
     reservedAmount: BN;
     perExecutionAmount: BN;
     erJobId: number[];
@@ -34,6 +44,12 @@ export interface ScheduleAccount {
     bump: number;
 }
 
+export interface ScheduleIndexEntry {
+    scheduleId: number[];
+    schedule: PublicKey;
+    status: ScheduleStatus;
+}
+
 export interface VeilConfig {
     erAuthority: PublicKey;
     governance: PublicKey;
@@ -46,6 +62,7 @@ export interface VeilConfig {
 
 export interface CreateScheduleParams {
     tokenMint: PublicKey;
+    vault?: PublicKey; // Defaults to the wallet's own vault for tokenMint
     scheduleId: number[];
     intervalSecs: number;
     reservedAmount: BN;
@@ -53,6 +70,10 @@ export interface CreateScheduleParams {
     merkleRoot: number[];
     totalRecipients: number;
     erJobId: number[];
+    missedPeriodPolicy?: MissedPeriodPolicy;
+    startAt?: number;
+    endAt?: number;
+    maxExecutions?: number;
 }
 
 export interface UpdateScheduleParams {
@@ -61,4 +82,8 @@ export interface UpdateScheduleParams {
     perExecutionAmount: BN;
     merkleRoot: number[];
     totalRecipients: number;
+    missedPeriodPolicy?: MissedPeriodPolicy;
+    startAt?: number;
+    endAt?: number | null; // null clears the end time, undefined keeps it
+    maxExecutions?: number | null; // null removes the cap, undefined keeps it
 }
@@ -5,6 +5,7 @@ import type { CreateScheduleParams, UpdateScheduleParams } from "./types";
 export const MIN_SCHEDULE_INTERVAL_SECS = 60 * 60;
 export const MAX_SCHEDULE_INTERVAL_SECS = 31 * 24 * 60 * 60;
 export const MAX_SCHEDULE_RECIPIENTS = 1024;
+export const MAX_PAYMENT_SPLITS = 4;
 
 export function assertValidCreateScheduleParams(params: CreateScheduleParams): void {
     if (params.scheduleId.length !== 32) {
@@ -37,6 +38,8 @@ export function assertValidCreateScheduleParams(params: CreateScheduleParams): v
     if (params.merkleRoot.length !== 32) {
         throw new Error("merkleRoot must be 32 bytes");
     }
+    assertValidScheduleWindow(params.startAt, params.endAt);
+    assertValidMaxExecutions(params.maxExecutions);
 }
 
 export function assertRecipientsMatchPerExecutionAmount(
@@ -88,4 +91,30 @@ export function assertValidUpdateScheduleParams(params: UpdateScheduleParams): v
     if (params.merkleRoot.length !== 32) {
         throw new Error("merkleRoot must be 32 bytes");
     }
+    assertValidScheduleWindow(params.startAt, params.endAt);
+    assertValidMaxExecutions(params.maxExecutions);
+}
+
+function assertValidScheduleWindow(startAt?: number, endAt?: number | null): void {
+    if (startAt !== undefined && (!Number.isInteger(startAt) || startAt < 0)) {
+        throw new Error("startAt must be a unix timestamp");
+    }
+    if (endAt !== undefined && endAt !== null) {
+        if (!Number.isInteger(endAt) || endAt < 0) {
+            throw new Error("endAt must be a unix timestamp");
+        }
+        if (startAt !== undefined && endAt < startAt) {
+            throw new Error("endAt cannot be before startAt");
+        }
+    }
+}
+
+function assertValidMaxExecutions(maxExecutions?: number | null): void {
+    if (
+        maxExecutions !== undefined &&
+        maxExecutions !== null &&
+        (!Number.isInteger(maxExecutions) || maxExecutions <= 0)
+    ) {
+        throw new Error("maxExecutions must be a positive integer");
+    }
 }